@@ -15,46 +15,211 @@ pub trait Quantity {
 
 pub mod prelude {
     pub use crate::{
-        arcminutes, arcseconds, astronomical_units, degrees, degrees_per_second,
-        degrees_per_second2, feet, feet2, feet_per_second, feet_per_second2, kelvin, kilograms,
-        kilograms_meter2, kilograms_per_meter3, kilograms_per_second, kilometers, knots, meters,
-        meters2, meters_per_second, meters_per_second2, miles, miles_per_hour, nautical_miles,
-        nautical_miles_per_hour, newton_meters, newtons, pascals, pdl, pounds_force, pounds_mass,
-        pounds_mass_per_second, pounds_per_feet3, pounds_square_foot, radians, radians_per_second,
-        radians_per_second2, scalar,
+        amperes, arcminutes, arcseconds, astronomical_units, atmospheres, bar,
+        british_thermal_units, btu, celsius, celsius_delta, centimeters, centimeters2,
+        centimeters3, centimeters_per_second, centipoise, centistokes, coulombs, cycles_per_minute,
+        days, define_quantity, define_unit, degrees, degrees_per_second, degrees_per_second2,
+        dynes, earth_masses, earth_masses_per_julian_year, earth_masses_per_parsec3,
+        earth_masses_per_second, fahrenheit, fahrenheit_delta, farads, fathoms, fathoms2, fathoms3,
+        fathoms_per_second, feet, feet2, feet3, feet3_per_second, feet_per_second,
+        feet_per_second2, feet_per_second3, foot_pounds, foot_pounds_per_second,
+        foot_pounds_per_slug_rankine, gradians, gradians_per_second, gradians_per_second2, grains,
+        grains_per_foot3, grains_per_hour, grains_per_second, grams, grams_per_centimeter3,
+        grams_per_hour, grams_per_kilonewton_second, grams_per_mole, grams_per_second,
+        hectopascals, henries, hertz, horsepower, inches, inches2, inches3, inches_of_mercury,
+        inches_per_second, joules, joules_per_kilogram_kelvin, julian_years, kelvin, kelvin_delta,
+        kgf, kilocalories, kilograms, kilograms_force, kilograms_meter2,
+        kilograms_meter2_per_second, kilograms_meters_per_second, kilograms_per_meter,
+        kilograms_per_meter2, kilograms_per_meter3, kilograms_per_mole,
+        kilograms_per_newton_second, kilograms_per_second, kilohertz, kilometers, kilomoles,
+        kilonewtons, kilopascals, kilowatt_hours, kilowatts, knots, light_years, light_years2,
+        light_years3, light_years_per_second, liters, liters_per_minute, megapascals, meters,
+        meters2, meters2_per_second, meters3, meters3_per_second, meters_per_second,
+        meters_per_second2, meters_per_second3, micrometers, microseconds, miles, miles_per_hour,
+        milliampere_hours, millibar, milligrams, milligrams_per_hour, milligrams_per_meter3,
+        milligrams_per_second, millimeters, millimeters2, millimeters3, millimeters_of_mercury,
+        millimeters_per_second, milliradians, milliradians_per_second, milliradians_per_second2,
+        milliseconds, mils, mils_per_second, mils_per_second2, minutes, moles, nanoseconds,
+        nautical_miles, nautical_miles_per_hour, newton_meter_seconds_per_radian, newton_meters,
+        newton_meters_per_radian, newton_seconds, newton_seconds_per_meter, newtons,
+        newtons_per_meter, ohms, ounces, ounces_per_hour, ounces_per_inch3, ounces_per_second,
+        parsecs, parsecs2, parsecs3, parsecs_per_second, pascal_seconds, pascals, pdl, poise,
+        poundals, pounds_force, pounds_force_per_inch, pounds_force_seconds_per_inch, pounds_mass,
+        pounds_mass_per_second, pounds_per_feet, pounds_per_feet2, pounds_per_feet3,
+        pounds_per_pound_force_hour, pounds_square_foot, pounds_square_inch, psi, ra_hours,
+        ra_hours_per_second, ra_hours_per_second2, ra_minutes, ra_minutes_per_second,
+        ra_minutes_per_second2, ra_seconds, ra_seconds_per_second, ra_seconds_per_second2, radians,
+        radians_per_second, radians_per_second2, rankine, rankine_delta, revolutions,
+        revolutions_per_minute, revolutions_per_second, revolutions_per_second2, rpm, scalar,
         scalar::{
-            acceleration::Acceleration,
+            acceleration::{Acceleration, STANDARD_GRAVITY},
+            amount::{Amount, AmountUnit},
             angle::{Angle, AngleUnit},
             angular_acceleration::AngularAcceleration,
+            angular_damping_coefficient::{
+                AngularDampingCoefficient, AngularDampingCoefficientUnit,
+            },
+            angular_momentum::AngularMomentum,
             angular_velocity::AngularVelocity,
             area::Area,
+            areal_density::ArealDensity,
+            capacitance::{Capacitance, CapacitanceUnit},
+            charge::{Charge, ChargeUnit},
+            current::{Current, CurrentUnit},
+            damping_coefficient::{DampingCoefficient, DampingCoefficientUnit},
             density::Density,
             dynamic_unit::DynamicUnits,
+            dynamic_viscosity::{reynolds_number, DynamicViscosity, DynamicViscosityUnit},
+            energy::{Energy, EnergyUnit},
             force::{Force, ForceUnit},
+            frequency::{Frequency, FrequencyUnit},
+            inductance::{Inductance, InductanceUnit},
+            jerk::Jerk,
+            kinematic_viscosity::{KinematicViscosity, KinematicViscosityUnit},
             length::{Length, LengthUnit},
+            linear_density::LinearDensity,
             mass::{Mass, MassUnit},
             mass_rate::MassRate,
-            pressure::{Pressure, PressureUnit},
+            molar_mass::{MolarMass, MolarMassUnit},
+            momentum::{Impulse, Momentum},
+            power::{Power, PowerUnit},
+            pressure::{dynamic_pressure, Pressure, PressureUnit},
+            resistance::{Resistance, ResistanceUnit},
             rotational_inertia::RotationalInertia,
+            specific_gas_constant::{
+                ideal_gas_amount, ideal_gas_density, ideal_gas_pressure, ideal_gas_temperature,
+                ideal_gas_volume, SpecificGasConstant, SpecificGasConstantUnit,
+                UNIVERSAL_GAS_CONSTANT,
+            },
+            specific_impulse::SpecificImpulse,
+            stiffness::{Stiffness, StiffnessUnit},
             temperature::{Temperature, TemperatureUnit},
+            temperature_delta::TemperatureDelta,
             time::{Time, TimeUnit},
             torque::Torque,
+            torsional_stiffness::{TorsionalStiffness, TorsionalStiffnessUnit},
+            tsfc::{Tsfc, TsfcUnit},
             velocity::Velocity,
             velocity_squared::VelocitySquared,
-            volume::Volume,
+            voltage::{Voltage, VoltageUnit},
+            volume::{Volume, VolumeUnit},
+            volume_rate::VolumeRate,
         },
-        seconds,
+        seconds, short_tons, short_tons_per_hour, short_tons_per_second, short_tons_per_yard3,
+        solar_masses, solar_masses_per_julian_year, solar_masses_per_parsec3,
+        solar_masses_per_second, specific_impulse, tonnes, tonnes_per_hour, tonnes_per_meter3,
+        tonnes_per_second, torr, turns,
         unit::{
-            arcminutes::ArcMinutes, arcseconds::ArcSeconds, astronomical_units::AstronomicalUnits,
-            celsius::Celsius, degrees::Degrees, fahrenheit::Fahrenheit, feet::Feet, hours::Hours,
-            kelvin::Kelvin, kilograms::Kilograms, kilometers::Kilometers, meters::Meters,
-            miles::Miles, nautical_miles::NauticalMiles, newtons::Newtons, pascals::Pascals,
-            pounds_force::PoundsForce, pounds_mass::PoundsMass,
-            pounds_square_foot::PoundsSquareFoot, radians::Radians, rankine::Rankine,
-            scalar::Scalar, seconds::Seconds, slugs::Slugs, Unit,
+            amperes::Amperes,
+            arcminutes::ArcMinutes,
+            arcseconds::ArcSeconds,
+            astronomical_units::AstronomicalUnits,
+            atmospheres::Atmospheres,
+            bar::Bar,
+            british_thermal_units::BritishThermalUnits,
+            celsius::Celsius,
+            centipoise::Centipoise,
+            centistokes::Centistokes,
+            coulombs::Coulombs,
+            cycles_per_minute::CyclesPerMinute,
+            days::Days,
+            degrees::Degrees,
+            dynes::Dynes,
+            earth_masses::EarthMasses,
+            fahrenheit::Fahrenheit,
+            farads::Farads,
+            fathoms::Fathoms,
+            feet::Feet,
+            foot_pounds::FootPounds,
+            foot_pounds_per_second::FootPoundsPerSecond,
+            foot_pounds_per_slug_rankine::FootPoundsPerSlugRankine,
+            gradians::Gradians,
+            grains::Grains,
+            grams::Grams,
+            grams_per_kilonewton_second::GramsPerKilonewtonSecond,
+            grams_per_mole::GramsPerMole,
+            henries::Henries,
+            hertz::Hertz,
+            horsepower::Horsepower,
+            hours::Hours,
+            hours_of_right_ascension::HoursOfRightAscension,
+            inches::Inches,
+            inches_of_mercury::InchesOfMercury,
+            joules::Joules,
+            joules_per_kilogram_kelvin::JoulesPerKilogramKelvin,
+            julian_years::JulianYears,
+            kelvin::Kelvin,
+            kilocalories::Kilocalories,
+            kilograms::Kilograms,
+            kilograms_force::KilogramsForce,
+            kilograms_per_mole::KilogramsPerMole,
+            kilograms_per_newton_second::KilogramsPerNewtonSecond,
+            kilohertz::Kilohertz,
+            kilometers::Kilometers,
+            kilomoles::Kilomoles,
+            kilowatt_hours::KilowattHours,
+            kilowatts::Kilowatts,
+            light_years::LightYears,
+            liters::Liters,
+            meters::Meters,
+            miles::Miles,
+            milliampere_hours::MilliampereHours,
+            millimeters_of_mercury::MillimetersOfMercury,
+            minutes::Minutes,
+            minutes_of_right_ascension::MinutesOfRightAscension,
+            moles::Moles,
+            nato_mils::NatoMils,
+            nautical_miles::NauticalMiles,
+            newton_meter_seconds_per_radian::NewtonMeterSecondsPerRadian,
+            newton_meters_per_radian::NewtonMetersPerRadian,
+            newton_seconds_per_meter::NewtonSecondsPerMeter,
+            newtons::Newtons,
+            newtons_per_meter::NewtonsPerMeter,
+            ohms::Ohms,
+            ounces::Ounces,
+            parsecs::Parsecs,
+            pascal_seconds::PascalSeconds,
+            pascals::Pascals,
+            poise::Poise,
+            poundals::Poundals,
+            pounds_force::PoundsForce,
+            pounds_force_per_inch::PoundsForcePerInch,
+            pounds_force_seconds_per_inch::PoundsForceSecondsPerInch,
+            pounds_mass::PoundsMass,
+            pounds_per_pound_force_hour::PoundsPerPoundForceHour,
+            pounds_square_foot::PoundsSquareFoot,
+            pounds_square_inch::PoundsSquareInch,
+            prefixed::{
+                Centi, Centimeters, Deci, Giga, Hecto, Hectopascals, Kilo, Kilonewtons,
+                Kilopascals, Mega, Megapascals, Micro, Micrometers, Microseconds, Milli, Millibar,
+                Milligrams, Millimeters, Milliradians, Milliseconds, Nano, Nanoseconds, Prefixed,
+                SiPrefix,
+            },
+            radians::Radians,
+            rankine::Rankine,
+            revolutions::Revolutions,
+            rpm::Rpm,
+            scalar::Scalar,
+            seconds::Seconds,
+            seconds_of_right_ascension::SecondsOfRightAscension,
+            short_tons::ShortTons,
+            slugs::Slugs,
+            solar_masses::SolarMasses,
+            square_meters_per_second::SquareMetersPerSecond,
+            tonnes::Tonnes,
+            torr::Torr,
+            us_gallons::UsGallons,
+            us_survey_feet::UsSurveyFeet,
+            volts::Volts,
+            watts::Watts,
+            weeks::Weeks,
+            yards::Yards,
+            Unit,
         },
+        us_gallons, us_gallons_per_minute, us_survey_feet, us_survey_feet2, us_survey_feet3,
+        us_survey_feet_per_second,
         vector::{pt3::Pt3, v3::V3},
-        Quantity,
+        volts, watts, weeks, yards, yards2, yards3, yards_per_second, Quantity,
     };
 }
 pub use crate::prelude::*;
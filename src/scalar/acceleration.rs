@@ -1,10 +1,18 @@
 use crate::{
     impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
-    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, DynamicUnits,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, DynamicUnits, Jerk,
     LengthUnit, Meters, Seconds, Time, TimeUnit, Velocity,
 };
 use ordered_float::OrderedFloat;
-use std::{fmt, fmt::Debug, marker::PhantomData, ops::Mul};
+use std::{
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
+
+/// Standard acceleration of gravity, in m/s^2 (exact, by definition).
+pub const STANDARD_GRAVITY: f64 = 9.806_65;
 
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Acceleration<UnitLength: LengthUnit, UnitTime: TimeUnit> {
@@ -33,7 +41,7 @@ where
     }
 
     pub fn g_number(&self) -> f64 {
-        Acceleration::<Meters, Seconds>::from(self).f64() / 9.806_65_f64
+        Acceleration::<Meters, Seconds>::from(self).f64() / STANDARD_GRAVITY
     }
 
     pub fn as_dyn(&self) -> DynamicUnits {
@@ -99,6 +107,33 @@ where
     }
 }
 
+impl<LA, TA, TB> Div<Time<TB>> for Acceleration<LA, TA>
+where
+    LA: LengthUnit,
+    TA: TimeUnit,
+    TB: TimeUnit,
+{
+    type Output = Jerk<LA, TA>;
+
+    fn div(self, other: Time<TB>) -> Self::Output {
+        Jerk::<LA, TA>::from(self.v.0 / Time::<TA>::from(&other).f64())
+    }
+}
+
+impl<LA, TA, LB, TB> Div<Jerk<LB, TB>> for Acceleration<LA, TA>
+where
+    LA: LengthUnit,
+    TA: TimeUnit,
+    LB: LengthUnit,
+    TB: TimeUnit,
+{
+    type Output = Time<TA>;
+
+    fn div(self, other: Jerk<LB, TB>) -> Self::Output {
+        Time::<TA>::from(self.v.0 / Jerk::<LA, TA>::from(&other).f64())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use crate::{
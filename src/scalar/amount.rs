@@ -0,0 +1,132 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, DynamicUnits,
+    Kilograms, KilogramsPerMole, Mass, MassUnit, MolarMass, MolarMassUnit, Moles, Scalar, Unit,
+};
+use ordered_float::OrderedFloat;
+use std::{
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
+
+pub trait AmountUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const MOLES_IN_UNIT: f64;
+}
+
+// amount of substance (an SI base dimension)
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Amount<Unit: AmountUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_quantity_ops!(Amount<A>, AmountUnit);
+supports_shift_ops!(Amount<A1>, Amount<A2>, AmountUnit);
+supports_scalar_ops!(Amount<A>, AmountUnit);
+supports_cancellation!(Amount<A1>, Amount<A2>, AmountUnit);
+supports_absdiffeq!(Amount<A>, AmountUnit);
+supports_value_type_conversion!(Amount<A>, AmountUnit, impl_value_type_conversions);
+
+impl<U> Amount<U>
+where
+    U: AmountUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new1o0::<Moles>(self.v * U::MOLES_IN_UNIT)
+    }
+}
+
+impl<Unit> fmt::Display for Amount<Unit>
+where
+    Unit: AmountUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a Amount<UnitA>> for Amount<UnitB>
+where
+    UnitA: AmountUnit,
+    UnitB: AmountUnit,
+{
+    fn from(v: &'a Amount<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::MOLES_IN_UNIT / UnitB::MOLES_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> From<DynamicUnits> for Amount<U>
+where
+    U: AmountUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new1o0::<Moles>(0f64.into()));
+        Self {
+            v: f / U::MOLES_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<A, B> Mul<MolarMass<B>> for Amount<A>
+where
+    A: AmountUnit,
+    B: MolarMassUnit,
+{
+    type Output = Mass<Kilograms>;
+
+    fn mul(self, rhs: MolarMass<B>) -> Self::Output {
+        let n = Amount::<Moles>::from(&self);
+        let molar_mass = MolarMass::<KilogramsPerMole>::from(&rhs);
+        Self::Output::from(n.f64() * molar_mass.f64())
+    }
+}
+
+impl<A, B> Div<Amount<B>> for Mass<A>
+where
+    A: MassUnit,
+    B: AmountUnit,
+{
+    type Output = MolarMass<KilogramsPerMole>;
+
+    fn div(self, rhs: Amount<B>) -> Self::Output {
+        let m = Mass::<Kilograms>::from(&self);
+        let n = Amount::<Moles>::from(&rhs);
+        Self::Output::from(m.f64() / n.f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{grams_per_mole, kilograms, kilomoles, moles, Amount, Kilomoles};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_amount() {
+        let n = kilomoles!(2);
+        println!("{n}");
+        assert_abs_diff_eq!(moles!(n), moles!(2_000));
+        assert_abs_diff_eq!(
+            moles!(2) * grams_per_mole!(16),
+            kilograms!(0.032),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            grams_per_mole!(kilograms!(0.032) / moles!(2)),
+            grams_per_mole!(16),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_amount_dyn() {
+        let n: Amount<Kilomoles> = kilomoles!(3).as_dyn().into();
+        assert_abs_diff_eq!(n, kilomoles!(3));
+    }
+}
@@ -149,7 +149,11 @@ where
 
 #[cfg(test)]
 mod test {
-    use crate::{arcminutes, arcseconds, degrees, radians};
+    use crate::{
+        arcminutes, arcseconds, degrees, degrees_per_second, gradians, milliradians, mils,
+        ra_hours, ra_minutes, ra_seconds, radians, radians_per_second, revolutions,
+        revolutions_per_minute, turns, Unit,
+    };
     use approx::assert_abs_diff_eq;
     use std::f64::consts::PI;
 
@@ -181,6 +185,37 @@ mod test {
         assert_abs_diff_eq!(arcseconds!(a).f32(), 60f32 * 60f32);
     }
 
+    #[test]
+    fn test_additional_angle_units() {
+        assert_abs_diff_eq!(degrees!(turns!(1.5)), degrees!(540), epsilon = 1e-12);
+        assert_abs_diff_eq!(revolutions!(radians!(PI)).f64(), 0.5);
+        assert_abs_diff_eq!(degrees!(gradians!(100)), degrees!(90), epsilon = 1e-12);
+        assert_abs_diff_eq!(mils!(degrees!(360)).f64(), 6_400., epsilon = 1e-9);
+        assert_abs_diff_eq!(milliradians!(radians!(0.25)).f64(), 250., epsilon = 1e-9);
+        assert_eq!(crate::Milliradians::UNIT_SHORT_NAME, "mrad");
+        assert_eq!(format!("{}", revolutions!(2)), "2rev");
+        assert_eq!(format!("{}", mils!(3)), "3mil");
+    }
+
+    #[test]
+    fn test_right_ascension() {
+        assert_abs_diff_eq!(degrees!(ra_hours!(1)), degrees!(15), epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            arcminutes!(ra_minutes!(1)),
+            arcminutes!(15),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(arcseconds!(ra_seconds!(1)), arcseconds!(15), epsilon = 1e-9);
+        assert_abs_diff_eq!(ra_seconds!(ra_hours!(24)).f64(), 86_400., epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_revolutions_per_minute() {
+        let w = revolutions_per_minute!(60);
+        assert_abs_diff_eq!(radians_per_second!(w).f64(), 2. * PI, epsilon = 1e-12);
+        assert_abs_diff_eq!(degrees_per_second!(w).f64(), 360., epsilon = 1e-9);
+    }
+
     #[test]
     fn test_wrapping() {
         assert_eq!(
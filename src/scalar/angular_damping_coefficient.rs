@@ -0,0 +1,149 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, AngleUnit,
+    AngularVelocity, DynamicUnits, ForceUnit, Kilograms, LengthUnit, Meters,
+    NewtonMeterSecondsPerRadian, Newtons, Radians, Scalar, Seconds, TimeUnit, Torque, Unit,
+};
+use ordered_float::OrderedFloat;
+use std::{
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
+
+pub trait AngularDampingCoefficientUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const NEWTON_METER_SECONDS_PER_RADIAN_IN_UNIT: f64;
+}
+
+// torque / angular velocity: mass * length^2 / time (radians cancel)
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct AngularDampingCoefficient<Unit: AngularDampingCoefficientUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_quantity_ops!(AngularDampingCoefficient<A>, AngularDampingCoefficientUnit);
+supports_shift_ops!(
+    AngularDampingCoefficient<A1>,
+    AngularDampingCoefficient<A2>,
+    AngularDampingCoefficientUnit
+);
+supports_scalar_ops!(AngularDampingCoefficient<A>, AngularDampingCoefficientUnit);
+supports_cancellation!(
+    AngularDampingCoefficient<A1>,
+    AngularDampingCoefficient<A2>,
+    AngularDampingCoefficientUnit
+);
+supports_absdiffeq!(AngularDampingCoefficient<A>, AngularDampingCoefficientUnit);
+supports_value_type_conversion!(
+    AngularDampingCoefficient<A>,
+    AngularDampingCoefficientUnit,
+    impl_value_type_conversions
+);
+
+impl<U> AngularDampingCoefficient<U>
+where
+    U: AngularDampingCoefficientUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new3o1::<Kilograms, Meters, Meters, Seconds>(
+            self.v * U::NEWTON_METER_SECONDS_PER_RADIAN_IN_UNIT,
+        )
+    }
+}
+
+impl<Unit> fmt::Display for AngularDampingCoefficient<Unit>
+where
+    Unit: AngularDampingCoefficientUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a AngularDampingCoefficient<UnitA>>
+    for AngularDampingCoefficient<UnitB>
+where
+    UnitA: AngularDampingCoefficientUnit,
+    UnitB: AngularDampingCoefficientUnit,
+{
+    fn from(v: &'a AngularDampingCoefficient<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::NEWTON_METER_SECONDS_PER_RADIAN_IN_UNIT
+                / UnitB::NEWTON_METER_SECONDS_PER_RADIAN_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> From<DynamicUnits> for AngularDampingCoefficient<U>
+where
+    U: AngularDampingCoefficientUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new3o1::<Kilograms, Meters, Meters, Seconds>(
+            0f64.into(),
+        ));
+        Self {
+            v: f / U::NEWTON_METER_SECONDS_PER_RADIAN_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<C, A, T> Mul<AngularVelocity<A, T>> for AngularDampingCoefficient<C>
+where
+    C: AngularDampingCoefficientUnit,
+    A: AngleUnit,
+    T: TimeUnit,
+{
+    type Output = Torque<Newtons, Meters>;
+
+    fn mul(self, rhs: AngularVelocity<A, T>) -> Self::Output {
+        let lhs = AngularDampingCoefficient::<NewtonMeterSecondsPerRadian>::from(&self);
+        let rhs = AngularVelocity::<Radians, Seconds>::from(&rhs);
+        Self::Output::from(lhs.f64() * rhs.f64())
+    }
+}
+
+impl<F, L, A, T> Div<AngularVelocity<A, T>> for Torque<F, L>
+where
+    F: ForceUnit,
+    L: LengthUnit,
+    A: AngleUnit,
+    T: TimeUnit,
+{
+    type Output = AngularDampingCoefficient<NewtonMeterSecondsPerRadian>;
+
+    fn div(self, rhs: AngularVelocity<A, T>) -> Self::Output {
+        let lhs = Torque::<Newtons, Meters>::from(&self);
+        let rhs = AngularVelocity::<Radians, Seconds>::from(&rhs);
+        Self::Output::from(lhs.f64() / rhs.f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        newton_meter_seconds_per_radian, newton_meters, radians_per_second,
+        AngularDampingCoefficient, NewtonMeterSecondsPerRadian,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_angular_damping_coefficient() {
+        let c = newton_meter_seconds_per_radian!(3);
+        println!("{c}");
+        assert_abs_diff_eq!(c * radians_per_second!(4), newton_meters!(12));
+        assert_abs_diff_eq!(newton_meters!(12) / radians_per_second!(4), c);
+    }
+
+    #[test]
+    fn test_angular_damping_coefficient_dyn() {
+        let c: AngularDampingCoefficient<NewtonMeterSecondsPerRadian> =
+            (newton_meters!(12).as_dyn() / radians_per_second!(4).as_dyn()).into();
+        assert_abs_diff_eq!(c, newton_meter_seconds_per_radian!(3));
+    }
+}
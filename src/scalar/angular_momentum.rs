@@ -0,0 +1,159 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, AngularVelocity,
+    DynamicUnits, Kilograms, LengthUnit, MassUnit, Meters, Newtons, Radians, RotationalInertia,
+    Seconds, Time, TimeUnit, Torque,
+};
+use ordered_float::OrderedFloat;
+use std::{fmt, fmt::Debug, marker::PhantomData, ops::Div};
+
+// mass * length^2 / time (radians being unitless)
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct AngularMomentum<UnitMass: MassUnit, UnitLength: LengthUnit, UnitTime: TimeUnit> {
+    v: OrderedFloat<f64>,
+    phantom_1: PhantomData<UnitMass>,
+    phantom_2: PhantomData<UnitLength>,
+    phantom_3: PhantomData<UnitTime>,
+}
+supports_quantity_ops!(AngularMomentum<A, B, C>, MassUnit, LengthUnit, TimeUnit);
+supports_shift_ops!(AngularMomentum<A1, B1, C1>, AngularMomentum<A2, B2, C2>, MassUnit, LengthUnit, TimeUnit);
+supports_scalar_ops!(AngularMomentum<A, B, C>, MassUnit, LengthUnit, TimeUnit);
+supports_cancellation!(AngularMomentum<A1, B1, C1>, AngularMomentum<A2, B2, C2>, MassUnit, LengthUnit, TimeUnit);
+supports_absdiffeq!(AngularMomentum<A, B, C>, MassUnit, LengthUnit, TimeUnit);
+supports_value_type_conversion!(AngularMomentum<A, B, C>, MassUnit, LengthUnit, TimeUnit, impl_value_type_conversions);
+
+impl<M, L, T> fmt::Display for AngularMomentum<M, L, T>
+where
+    M: MassUnit,
+    L: LengthUnit,
+    T: TimeUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(
+            f,
+            "{}*{}^2/{}",
+            M::UNIT_SHORT_NAME,
+            L::UNIT_SHORT_NAME,
+            T::UNIT_SHORT_NAME
+        )
+    }
+}
+
+impl<'a, MA, LA, TA, MB, LB, TB> From<&'a AngularMomentum<MA, LA, TA>>
+    for AngularMomentum<MB, LB, TB>
+where
+    MA: MassUnit,
+    LA: LengthUnit,
+    TA: TimeUnit,
+    MB: MassUnit,
+    LB: LengthUnit,
+    TB: TimeUnit,
+{
+    fn from(v: &'a AngularMomentum<MA, LA, TA>) -> Self {
+        let mass_ratio = MA::GRAMS_IN_UNIT / MB::GRAMS_IN_UNIT;
+        let length_ratio = LA::METERS_IN_UNIT / LB::METERS_IN_UNIT;
+        let time_ratio = TB::SECONDS_IN_UNIT / TA::SECONDS_IN_UNIT;
+        Self {
+            v: v.v * mass_ratio * length_ratio * length_ratio * time_ratio,
+            phantom_1: PhantomData,
+            phantom_2: PhantomData,
+            phantom_3: PhantomData,
+        }
+    }
+}
+
+impl<M, L, T> AngularMomentum<M, L, T>
+where
+    M: MassUnit,
+    L: LengthUnit,
+    T: TimeUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new3o1::<M, L, L, T>(self.v)
+    }
+}
+
+impl<M, L, T> From<DynamicUnits> for AngularMomentum<M, L, T>
+where
+    M: MassUnit,
+    L: LengthUnit,
+    T: TimeUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new3o1::<M, L, L, T>(0f64.into()));
+        Self {
+            v: f,
+            phantom_1: PhantomData,
+            phantom_2: PhantomData,
+            phantom_3: PhantomData,
+        }
+    }
+}
+
+impl<MA, LA, TA, MB, LB> Div<RotationalInertia<MB, LB>> for AngularMomentum<MA, LA, TA>
+where
+    MA: MassUnit,
+    LA: LengthUnit,
+    TA: TimeUnit,
+    MB: MassUnit,
+    LB: LengthUnit,
+{
+    type Output = AngularVelocity<Radians, TA>;
+
+    fn div(self, other: RotationalInertia<MB, LB>) -> Self::Output {
+        let inertia = RotationalInertia::<MA, LA>::from(&other);
+        Self::Output::from(self.v.0 / inertia.f64())
+    }
+}
+
+impl<MA, LA, TA, TB> Div<Time<TB>> for AngularMomentum<MA, LA, TA>
+where
+    MA: MassUnit,
+    LA: LengthUnit,
+    TA: TimeUnit,
+    TB: TimeUnit,
+{
+    type Output = Torque<Newtons, Meters>;
+
+    fn div(self, other: Time<TB>) -> Self::Output {
+        let momentum = AngularMomentum::<Kilograms, Meters, Seconds>::from(&self);
+        Self::Output::from(momentum.f64() / Time::<Seconds>::from(&other).f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        kilograms_meter2, kilograms_meter2_per_second, newton_meters, radians_per_second, scalar,
+        seconds, AngularMomentum, Feet, PoundsMass, Seconds,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_angular_momentum() {
+        let l = kilograms_meter2_per_second!(10);
+        let imperial = AngularMomentum::<PoundsMass, Feet, Seconds>::from(&l);
+        println!("{l}");
+        println!("{imperial}");
+        assert_abs_diff_eq!(
+            kilograms_meter2_per_second!(imperial),
+            l,
+            epsilon = 0.000_001
+        );
+        assert_abs_diff_eq!(
+            kilograms_meter2_per_second!(2) * scalar!(2),
+            kilograms_meter2_per_second!(4)
+        );
+    }
+
+    #[test]
+    fn test_angular_momentum_relations() {
+        let i = kilograms_meter2!(4);
+        let l = i * radians_per_second!(3);
+        assert_abs_diff_eq!(l, kilograms_meter2_per_second!(12));
+        assert_abs_diff_eq!(l / i, radians_per_second!(3));
+        assert_abs_diff_eq!(l / seconds!(4), newton_meters!(3));
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Area, Density,
+    DynamicUnits, Length, LengthUnit, Mass, MassUnit,
+};
+use ordered_float::OrderedFloat;
+use std::{
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
+
+// mass / length^2
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ArealDensity<UnitMass: MassUnit, UnitLength: LengthUnit> {
+    v: OrderedFloat<f64>,
+    phantom_1: PhantomData<UnitMass>,
+    phantom_2: PhantomData<UnitLength>,
+}
+supports_absdiffeq!(ArealDensity<A, B>, MassUnit, LengthUnit);
+supports_quantity_ops!(ArealDensity<A, B>, MassUnit, LengthUnit);
+supports_scalar_ops!(ArealDensity<A, B>, MassUnit, LengthUnit);
+supports_cancellation!(ArealDensity<A1, B1>, ArealDensity<A2, B2>, MassUnit, LengthUnit);
+supports_shift_ops!(ArealDensity<A1, B1>, ArealDensity<A2, B2>, MassUnit, LengthUnit);
+supports_value_type_conversion!(ArealDensity<A, B>, MassUnit, LengthUnit, impl_value_type_conversions);
+
+impl<M, L> fmt::Display for ArealDensity<M, L>
+where
+    M: MassUnit,
+    L: LengthUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}/{}^2", M::UNIT_SHORT_NAME, L::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, MA, LA, MB, LB> From<&'a ArealDensity<MB, LB>> for ArealDensity<MA, LA>
+where
+    MA: MassUnit,
+    LA: LengthUnit,
+    MB: MassUnit,
+    LB: LengthUnit,
+{
+    fn from(v: &'a ArealDensity<MB, LB>) -> Self {
+        let mass_ratio = MB::GRAMS_IN_UNIT / MA::GRAMS_IN_UNIT;
+        let length_ratio = LA::METERS_IN_UNIT / LB::METERS_IN_UNIT;
+        Self {
+            v: v.v * mass_ratio * length_ratio * length_ratio,
+            phantom_1: PhantomData,
+            phantom_2: PhantomData,
+        }
+    }
+}
+
+impl<MA, LA> ArealDensity<MA, LA>
+where
+    MA: MassUnit,
+    LA: LengthUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new1o2::<MA, LA, LA>(self.v)
+    }
+}
+
+impl<M, L> From<DynamicUnits> for ArealDensity<M, L>
+where
+    M: MassUnit,
+    L: LengthUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new1o2::<M, L, L>(0f64.into()));
+        Self {
+            v: f,
+            phantom_1: PhantomData,
+            phantom_2: PhantomData,
+        }
+    }
+}
+
+impl<M, L, LA> Mul<Area<LA>> for ArealDensity<M, L>
+where
+    M: MassUnit,
+    L: LengthUnit,
+    LA: LengthUnit,
+{
+    type Output = Mass<M>;
+
+    fn mul(self, rhs: Area<LA>) -> Self::Output {
+        Mass::<M>::from(self.v.0 * Area::<L>::from(&rhs).f64())
+    }
+}
+
+impl<M, L, LA> Div<Length<LA>> for ArealDensity<M, L>
+where
+    M: MassUnit,
+    L: LengthUnit,
+    LA: LengthUnit,
+{
+    type Output = Density<M, L>;
+
+    fn div(self, rhs: Length<LA>) -> Self::Output {
+        Density::<M, L>::from(self.v.0 / Length::<L>::from(&rhs).f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        feet2, kilograms, kilograms_per_meter2, kilograms_per_meter3, meters, meters2, pounds_mass,
+        pounds_per_feet2, ArealDensity, Kilograms, Meters,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_areal_density() {
+        let rho_a = kilograms_per_meter2!(10);
+        println!("{rho_a}");
+        assert_abs_diff_eq!(
+            pounds_per_feet2!(rho_a),
+            pounds_per_feet2!(2.048_161),
+            epsilon = 0.000_001
+        );
+        assert_abs_diff_eq!(rho_a * meters2!(3), kilograms!(30));
+        assert_abs_diff_eq!(
+            pounds_mass!(pounds_per_feet2!(2) * feet2!(3)),
+            pounds_mass!(6),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(rho_a / meters!(2), kilograms_per_meter3!(5));
+    }
+
+    #[test]
+    fn test_areal_density_dyn() {
+        let rho_a: ArealDensity<Kilograms, Meters> =
+            (kilograms!(10).as_dyn() / meters2!(2).as_dyn()).into();
+        assert_abs_diff_eq!(rho_a, kilograms_per_meter2!(5));
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Amperes, Charge,
+    Coulombs, DynamicUnits, Farads, Kilograms, Meters, Scalar, Seconds, Unit, Voltage, VoltageUnit,
+    Volts,
+};
+use ordered_float::OrderedFloat;
+use std::{fmt, fmt::Debug, marker::PhantomData, ops::Mul};
+
+pub trait CapacitanceUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const FARADS_IN_UNIT: f64;
+}
+
+// charge / voltage: time^4 * current^2 / (mass * length^2)
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Capacitance<Unit: CapacitanceUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_quantity_ops!(Capacitance<A>, CapacitanceUnit);
+supports_shift_ops!(Capacitance<A1>, Capacitance<A2>, CapacitanceUnit);
+supports_scalar_ops!(Capacitance<A>, CapacitanceUnit);
+supports_cancellation!(Capacitance<A1>, Capacitance<A2>, CapacitanceUnit);
+supports_absdiffeq!(Capacitance<A>, CapacitanceUnit);
+supports_value_type_conversion!(Capacitance<A>, CapacitanceUnit, impl_value_type_conversions);
+
+fn dyn_units(v: OrderedFloat<f64>) -> DynamicUnits {
+    DynamicUnits::new2o0::<Amperes, Amperes>(v)
+        * DynamicUnits::new2o0::<Seconds, Seconds>(1f64.into())
+        * DynamicUnits::new2o0::<Seconds, Seconds>(1f64.into())
+        / DynamicUnits::new3o0::<Kilograms, Meters, Meters>(1f64.into())
+}
+
+impl<U> Capacitance<U>
+where
+    U: CapacitanceUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        dyn_units(self.v * U::FARADS_IN_UNIT)
+    }
+}
+
+impl<Unit> fmt::Display for Capacitance<Unit>
+where
+    Unit: CapacitanceUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a Capacitance<UnitA>> for Capacitance<UnitB>
+where
+    UnitA: CapacitanceUnit,
+    UnitB: CapacitanceUnit,
+{
+    fn from(v: &'a Capacitance<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::FARADS_IN_UNIT / UnitB::FARADS_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> From<DynamicUnits> for Capacitance<U>
+where
+    U: CapacitanceUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(dyn_units(0f64.into()));
+        Self {
+            v: f / U::FARADS_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<A, B> Mul<Voltage<B>> for Capacitance<A>
+where
+    A: CapacitanceUnit,
+    B: VoltageUnit,
+{
+    type Output = Charge<Coulombs>;
+
+    fn mul(self, rhs: Voltage<B>) -> Self::Output {
+        let lhs = Capacitance::<Farads>::from(&self);
+        let rhs = Voltage::<Volts>::from(&rhs);
+        Self::Output::from(lhs.f64() * rhs.f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{coulombs, farads, ohms, seconds, volts, Capacitance, Farads};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_capacitance() {
+        let c = farads!(2);
+        println!("{c}");
+        assert_abs_diff_eq!(c * volts!(3), coulombs!(6));
+    }
+
+    #[test]
+    fn test_capacitance_dyn() {
+        let c: Capacitance<Farads> = (seconds!(2).as_dyn() / ohms!(4).as_dyn()).into();
+        assert_abs_diff_eq!(c, farads!(0.5));
+    }
+}
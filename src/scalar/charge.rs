@@ -0,0 +1,130 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Amperes, Capacitance,
+    Coulombs, Current, DynamicUnits, Farads, Scalar, Seconds, Time, TimeUnit, Unit, Voltage,
+    VoltageUnit, Volts,
+};
+use ordered_float::OrderedFloat;
+use std::{fmt, fmt::Debug, marker::PhantomData, ops::Div};
+
+pub trait ChargeUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const COULOMBS_IN_UNIT: f64;
+}
+
+// current * time
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Charge<Unit: ChargeUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_quantity_ops!(Charge<A>, ChargeUnit);
+supports_shift_ops!(Charge<A1>, Charge<A2>, ChargeUnit);
+supports_scalar_ops!(Charge<A>, ChargeUnit);
+supports_cancellation!(Charge<A1>, Charge<A2>, ChargeUnit);
+supports_absdiffeq!(Charge<A>, ChargeUnit);
+supports_value_type_conversion!(Charge<A>, ChargeUnit, impl_value_type_conversions);
+
+impl<U> Charge<U>
+where
+    U: ChargeUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new2o0::<Amperes, Seconds>(self.v * U::COULOMBS_IN_UNIT)
+    }
+}
+
+impl<Unit> fmt::Display for Charge<Unit>
+where
+    Unit: ChargeUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a Charge<UnitA>> for Charge<UnitB>
+where
+    UnitA: ChargeUnit,
+    UnitB: ChargeUnit,
+{
+    fn from(v: &'a Charge<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::COULOMBS_IN_UNIT / UnitB::COULOMBS_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> From<DynamicUnits> for Charge<U>
+where
+    U: ChargeUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new2o0::<Amperes, Seconds>(0f64.into()));
+        Self {
+            v: f / U::COULOMBS_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<A, B> Div<Time<B>> for Charge<A>
+where
+    A: ChargeUnit,
+    B: TimeUnit,
+{
+    type Output = Current<Amperes>;
+
+    fn div(self, rhs: Time<B>) -> Self::Output {
+        let lhs = Charge::<Coulombs>::from(&self);
+        let rhs = Time::<Seconds>::from(&rhs);
+        Self::Output::from(lhs.f64() / rhs.f64())
+    }
+}
+
+impl<A, B> Div<Voltage<B>> for Charge<A>
+where
+    A: ChargeUnit,
+    B: VoltageUnit,
+{
+    type Output = Capacitance<Farads>;
+
+    fn div(self, rhs: Voltage<B>) -> Self::Output {
+        let lhs = Charge::<Coulombs>::from(&self);
+        let rhs = Voltage::<Volts>::from(&rhs);
+        Self::Output::from(lhs.f64() / rhs.f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        amperes, coulombs, farads, hours, milliampere_hours, seconds, volts, Charge, Coulombs,
+        MilliampereHours,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_charge() {
+        let q = milliampere_hours!(1_000);
+        println!("{q}");
+        assert_abs_diff_eq!(coulombs!(q), coulombs!(3_600), epsilon = 0.000_001);
+        assert_abs_diff_eq!(
+            milliampere_hours!(amperes!(2) * hours!(1)),
+            milliampere_hours!(2_000),
+            epsilon = 0.000_001
+        );
+        assert_abs_diff_eq!(coulombs!(6) / seconds!(3), amperes!(2));
+        assert_abs_diff_eq!(coulombs!(6) / volts!(3), farads!(2));
+    }
+
+    #[test]
+    fn test_charge_dyn() {
+        let q: Charge<MilliampereHours> = milliampere_hours!(500).as_dyn().into();
+        assert_abs_diff_eq!(q, milliampere_hours!(500), epsilon = 0.000_001);
+        let q: Charge<Coulombs> = milliampere_hours!(1).as_dyn().into();
+        assert_abs_diff_eq!(q, coulombs!(3.6), epsilon = 0.000_001);
+    }
+}
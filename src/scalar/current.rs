@@ -0,0 +1,120 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Amperes, Charge,
+    Coulombs, DynamicUnits, Ohms, Resistance, ResistanceUnit, Scalar, Seconds, Time, TimeUnit,
+    Unit, Voltage, Volts,
+};
+use ordered_float::OrderedFloat;
+use std::{fmt, fmt::Debug, marker::PhantomData, ops::Mul};
+
+pub trait CurrentUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const AMPERES_IN_UNIT: f64;
+}
+
+// electric current (an SI base dimension)
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Current<Unit: CurrentUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_quantity_ops!(Current<A>, CurrentUnit);
+supports_shift_ops!(Current<A1>, Current<A2>, CurrentUnit);
+supports_scalar_ops!(Current<A>, CurrentUnit);
+supports_cancellation!(Current<A1>, Current<A2>, CurrentUnit);
+supports_absdiffeq!(Current<A>, CurrentUnit);
+supports_value_type_conversion!(Current<A>, CurrentUnit, impl_value_type_conversions);
+
+impl<U> Current<U>
+where
+    U: CurrentUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new1o0::<Amperes>(self.v * U::AMPERES_IN_UNIT)
+    }
+}
+
+impl<Unit> fmt::Display for Current<Unit>
+where
+    Unit: CurrentUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a Current<UnitA>> for Current<UnitB>
+where
+    UnitA: CurrentUnit,
+    UnitB: CurrentUnit,
+{
+    fn from(v: &'a Current<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::AMPERES_IN_UNIT / UnitB::AMPERES_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> From<DynamicUnits> for Current<U>
+where
+    U: CurrentUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new1o0::<Amperes>(0f64.into()));
+        Self {
+            v: f / U::AMPERES_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<A, B> Mul<Time<B>> for Current<A>
+where
+    A: CurrentUnit,
+    B: TimeUnit,
+{
+    type Output = Charge<Coulombs>;
+
+    fn mul(self, rhs: Time<B>) -> Self::Output {
+        let lhs = Current::<Amperes>::from(&self);
+        let rhs = Time::<Seconds>::from(&rhs);
+        Self::Output::from(lhs.f64() * rhs.f64())
+    }
+}
+
+impl<A, B> Mul<Resistance<B>> for Current<A>
+where
+    A: CurrentUnit,
+    B: ResistanceUnit,
+{
+    type Output = Voltage<Volts>;
+
+    fn mul(self, rhs: Resistance<B>) -> Self::Output {
+        let lhs = Current::<Amperes>::from(&self);
+        let rhs = Resistance::<Ohms>::from(&rhs);
+        Self::Output::from(lhs.f64() * rhs.f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{amperes, coulombs, ohms, scalar, seconds, volts, Amperes, Current};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_current() {
+        let i = amperes!(2);
+        println!("{i}");
+        assert_abs_diff_eq!(i * scalar!(2), amperes!(4));
+        assert_abs_diff_eq!(i * seconds!(3), coulombs!(6));
+        assert_abs_diff_eq!(i * ohms!(5), volts!(10));
+    }
+
+    #[test]
+    fn test_current_dyn() {
+        let i: Current<Amperes> = (coulombs!(10).as_dyn() / seconds!(2).as_dyn()).into();
+        assert_abs_diff_eq!(i, amperes!(5));
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, DynamicUnits, Force,
+    ForceUnit, Kilograms, LengthUnit, Meters, NewtonSecondsPerMeter, Newtons, Scalar, Seconds,
+    TimeUnit, Unit, Velocity,
+};
+use ordered_float::OrderedFloat;
+use std::{
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
+
+pub trait DampingCoefficientUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const NEWTON_SECONDS_PER_METER_IN_UNIT: f64;
+}
+
+// force / velocity: mass / time
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct DampingCoefficient<Unit: DampingCoefficientUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_quantity_ops!(DampingCoefficient<A>, DampingCoefficientUnit);
+supports_shift_ops!(
+    DampingCoefficient<A1>,
+    DampingCoefficient<A2>,
+    DampingCoefficientUnit
+);
+supports_scalar_ops!(DampingCoefficient<A>, DampingCoefficientUnit);
+supports_cancellation!(
+    DampingCoefficient<A1>,
+    DampingCoefficient<A2>,
+    DampingCoefficientUnit
+);
+supports_absdiffeq!(DampingCoefficient<A>, DampingCoefficientUnit);
+supports_value_type_conversion!(
+    DampingCoefficient<A>,
+    DampingCoefficientUnit,
+    impl_value_type_conversions
+);
+
+impl<U> DampingCoefficient<U>
+where
+    U: DampingCoefficientUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new1o1::<Kilograms, Seconds>(self.v * U::NEWTON_SECONDS_PER_METER_IN_UNIT)
+    }
+}
+
+impl<Unit> fmt::Display for DampingCoefficient<Unit>
+where
+    Unit: DampingCoefficientUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a DampingCoefficient<UnitA>> for DampingCoefficient<UnitB>
+where
+    UnitA: DampingCoefficientUnit,
+    UnitB: DampingCoefficientUnit,
+{
+    fn from(v: &'a DampingCoefficient<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::NEWTON_SECONDS_PER_METER_IN_UNIT
+                / UnitB::NEWTON_SECONDS_PER_METER_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> From<DynamicUnits> for DampingCoefficient<U>
+where
+    U: DampingCoefficientUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new1o1::<Kilograms, Seconds>(0f64.into()));
+        Self {
+            v: f / U::NEWTON_SECONDS_PER_METER_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<C, L, T> Mul<Velocity<L, T>> for DampingCoefficient<C>
+where
+    C: DampingCoefficientUnit,
+    L: LengthUnit,
+    T: TimeUnit,
+{
+    type Output = Force<Newtons>;
+
+    fn mul(self, rhs: Velocity<L, T>) -> Self::Output {
+        let lhs = DampingCoefficient::<NewtonSecondsPerMeter>::from(&self);
+        let rhs = Velocity::<Meters, Seconds>::from(&rhs);
+        Self::Output::from(lhs.f64() * rhs.f64())
+    }
+}
+
+impl<F, L, T> Div<Velocity<L, T>> for Force<F>
+where
+    F: ForceUnit,
+    L: LengthUnit,
+    T: TimeUnit,
+{
+    type Output = DampingCoefficient<NewtonSecondsPerMeter>;
+
+    fn div(self, rhs: Velocity<L, T>) -> Self::Output {
+        let lhs = Force::<Newtons>::from(&self);
+        let rhs = Velocity::<Meters, Seconds>::from(&rhs);
+        Self::Output::from(lhs.f64() / rhs.f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        meters_per_second, newton_seconds_per_meter, newtons, pounds_force_seconds_per_inch,
+        DampingCoefficient, NewtonSecondsPerMeter,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_damping_coefficient() {
+        let c = newton_seconds_per_meter!(50);
+        println!("{c}");
+        assert_abs_diff_eq!(c * meters_per_second!(2), newtons!(100));
+        assert_abs_diff_eq!(newtons!(100) / meters_per_second!(2), c);
+        assert_abs_diff_eq!(
+            newton_seconds_per_meter!(pounds_force_seconds_per_inch!(1)),
+            newton_seconds_per_meter!(175.127),
+            epsilon = 0.001
+        );
+    }
+
+    #[test]
+    fn test_damping_coefficient_dyn() {
+        let c: DampingCoefficient<NewtonSecondsPerMeter> =
+            (newtons!(10).as_dyn() / meters_per_second!(2).as_dyn()).into();
+        assert_abs_diff_eq!(c, newton_seconds_per_meter!(5));
+    }
+}
@@ -0,0 +1,301 @@
+/// Declare a new quantity type with one or two unit parameters, along with the
+/// operator, comparison, and value type support that the built-in quantities have,
+/// `Display`, conversion between units, and conversion to and from `DynamicUnits`.
+///
+/// Each unit parameter gives the constant on its unit trait that scales it and the
+/// power it is raised to in the quantity. The `dynamic` entry names the
+/// `DynamicUnits` constructor and the units that make up the quantity's signature.
+/// Each `{}` in `display` is filled with the short name of the unit parameters, in
+/// order.
+///
+/// ```
+/// use absolute_unit::prelude::*;
+///
+/// absolute_unit::define_quantity! {
+///     /// The rate of change of jerk.
+///     pub Snap<L: LengthUnit = METERS_IN_UNIT ^ 1, T: TimeUnit = SECONDS_IN_UNIT ^ -4> {
+///         dynamic: new1o4<L, T, T, T, T>,
+///         display: "{}/{}^4",
+///     }
+/// }
+///
+/// fn main() {
+///     let s = Snap::<Meters, Seconds>::from(2);
+///     assert_eq!(format!("{s}"), "2m/s^4");
+///     assert_eq!(Snap::<Meters, Milliseconds>::from(&s).f64(), 2e-12);
+///     let j: Jerk<Meters, Seconds> = (s.as_dyn() * seconds!(3).as_dyn()).into();
+///     assert_eq!(j, meters_per_second3!(6));
+/// }
+/// ```
+#[macro_export]
+macro_rules! define_quantity {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $Quantity:ident<$A:ident: $UnitA:path = $scale_a:ident ^ $power_a:literal> {
+            dynamic: $new:ident<$($Dyn:ident),+>,
+            display: $display:literal $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+        $vis struct $Quantity<$A: $UnitA> {
+            v: $crate::ordered_float::OrderedFloat<f64>,
+            phantom_1: ::std::marker::PhantomData<$A>,
+        }
+
+        // The support macros expect these names to be in scope.
+        const _: () = {
+            use $crate::{impl_value_type_conversions, ordered_float::OrderedFloat, Scalar};
+            use ::std::marker::PhantomData;
+
+            $crate::supports_quantity_ops!($Quantity<A>, $UnitA);
+            $crate::supports_shift_ops!($Quantity<A1>, $Quantity<A2>, $UnitA);
+            $crate::supports_scalar_ops!($Quantity<A>, $UnitA);
+            $crate::supports_cancellation!($Quantity<A1>, $Quantity<A2>, $UnitA);
+            $crate::supports_absdiffeq!($Quantity<A>, $UnitA);
+            $crate::supports_value_type_conversion!(
+                $Quantity<A>,
+                $UnitA,
+                impl_value_type_conversions
+            );
+        };
+
+        impl<$A> $Quantity<$A>
+        where
+            $A: $UnitA,
+        {
+            pub const fn new(v: f64) -> Self {
+                Self {
+                    v: $crate::ordered_float::OrderedFloat(v),
+                    phantom_1: ::std::marker::PhantomData,
+                }
+            }
+
+            pub fn as_dyn(&self) -> $crate::DynamicUnits {
+                $crate::DynamicUnits::$new::<$($Dyn),+>(self.v)
+            }
+        }
+
+        impl<$A> ::std::fmt::Display for $Quantity<$A>
+        where
+            $A: $UnitA,
+        {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::std::fmt::Display::fmt(&self.v.0, f)?;
+                write!(f, $display, <$A as $crate::Unit>::UNIT_SHORT_NAME)
+            }
+        }
+
+        impl<'a, A1, A2> From<&'a $Quantity<A1>> for $Quantity<A2>
+        where
+            A1: $UnitA,
+            A2: $UnitA,
+        {
+            fn from(v: &'a $Quantity<A1>) -> Self {
+                let ratio_a = (A1::$scale_a / A2::$scale_a).powi($power_a);
+                Self {
+                    v: v.v * ratio_a,
+                    phantom_1: ::std::marker::PhantomData,
+                }
+            }
+        }
+
+        impl<$A> From<$crate::DynamicUnits> for $Quantity<$A>
+        where
+            $A: $UnitA,
+        {
+            fn from(v: $crate::DynamicUnits) -> Self {
+                let f = v.ordered_float();
+                v.assert_units_equal($crate::DynamicUnits::$new::<$($Dyn),+>(0f64.into()));
+                Self {
+                    v: f,
+                    phantom_1: ::std::marker::PhantomData,
+                }
+            }
+        }
+    };
+
+    (
+        $(#[$meta:meta])*
+        $vis:vis $Quantity:ident<
+            $A:ident: $UnitA:path = $scale_a:ident ^ $power_a:literal,
+            $B:ident: $UnitB:path = $scale_b:ident ^ $power_b:literal $(,)?
+        > {
+            dynamic: $new:ident<$($Dyn:ident),+>,
+            display: $display:literal $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+        $vis struct $Quantity<$A: $UnitA, $B: $UnitB> {
+            v: $crate::ordered_float::OrderedFloat<f64>,
+            phantom_1: ::std::marker::PhantomData<$A>,
+            phantom_2: ::std::marker::PhantomData<$B>,
+        }
+
+        // The support macros expect these names to be in scope.
+        const _: () = {
+            use $crate::{impl_value_type_conversions, ordered_float::OrderedFloat};
+            use ::std::marker::PhantomData;
+
+            $crate::supports_quantity_ops!($Quantity<A, B>, $UnitA, $UnitB);
+            $crate::supports_shift_ops!($Quantity<A1, B1>, $Quantity<A2, B2>, $UnitA, $UnitB);
+            $crate::supports_scalar_ops!($Quantity<A, B>, $UnitA, $UnitB);
+            $crate::supports_cancellation!($Quantity<A1, B1>, $Quantity<A2, B2>, $UnitA, $UnitB);
+            $crate::supports_absdiffeq!($Quantity<A, B>, $UnitA, $UnitB);
+            $crate::supports_value_type_conversion!(
+                $Quantity<A, B>,
+                $UnitA,
+                $UnitB,
+                impl_value_type_conversions
+            );
+        };
+
+        impl<$A, $B> $Quantity<$A, $B>
+        where
+            $A: $UnitA,
+            $B: $UnitB,
+        {
+            pub const fn new(v: f64) -> Self {
+                Self {
+                    v: $crate::ordered_float::OrderedFloat(v),
+                    phantom_1: ::std::marker::PhantomData,
+                    phantom_2: ::std::marker::PhantomData,
+                }
+            }
+
+            pub fn as_dyn(&self) -> $crate::DynamicUnits {
+                $crate::DynamicUnits::$new::<$($Dyn),+>(self.v)
+            }
+        }
+
+        impl<$A, $B> ::std::fmt::Display for $Quantity<$A, $B>
+        where
+            $A: $UnitA,
+            $B: $UnitB,
+        {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                ::std::fmt::Display::fmt(&self.v.0, f)?;
+                write!(
+                    f,
+                    $display,
+                    <$A as $crate::Unit>::UNIT_SHORT_NAME,
+                    <$B as $crate::Unit>::UNIT_SHORT_NAME
+                )
+            }
+        }
+
+        impl<'a, A1, B1, A2, B2> From<&'a $Quantity<A1, B1>> for $Quantity<A2, B2>
+        where
+            A1: $UnitA,
+            B1: $UnitB,
+            A2: $UnitA,
+            B2: $UnitB,
+        {
+            fn from(v: &'a $Quantity<A1, B1>) -> Self {
+                let ratio_a = (A1::$scale_a / A2::$scale_a).powi($power_a);
+                let ratio_b = (B1::$scale_b / B2::$scale_b).powi($power_b);
+                Self {
+                    v: v.v * ratio_a * ratio_b,
+                    phantom_1: ::std::marker::PhantomData,
+                    phantom_2: ::std::marker::PhantomData,
+                }
+            }
+        }
+
+        impl<$A, $B> From<$crate::DynamicUnits> for $Quantity<$A, $B>
+        where
+            $A: $UnitA,
+            $B: $UnitB,
+        {
+            fn from(v: $crate::DynamicUnits) -> Self {
+                let f = v.ordered_float();
+                v.assert_units_equal($crate::DynamicUnits::$new::<$($Dyn),+>(0f64.into()));
+                Self {
+                    v: f,
+                    phantom_1: ::std::marker::PhantomData,
+                    phantom_2: ::std::marker::PhantomData,
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+#[allow(dead_code)]
+mod test {
+    use crate::{
+        feet, meters, meters_per_second3, scalar, seconds, DynamicUnits, Jerk, LengthUnit, Meters,
+        Milliseconds, Quantity, Seconds, TimeUnit, Unit,
+    };
+    use approx::assert_abs_diff_eq;
+    use std::fmt::Debug;
+
+    pub trait LuminosityUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+        const WATTS_IN_UNIT: f64;
+    }
+
+    crate::define_unit! {
+        SolarLuminosities {
+            name: "solar luminosities",
+            short_name: "L☉",
+        }
+        impl LuminosityUnit {
+            const WATTS_IN_UNIT: f64 = 3.828e26;
+        }
+    }
+
+    crate::define_unit! {
+        Ergs {
+            name: "ergs per second",
+            short_name: "erg/s",
+        }
+        impl LuminosityUnit {
+            const WATTS_IN_UNIT: f64 = 1e-7;
+        }
+    }
+
+    define_quantity! {
+        pub(crate) Luminosity<U: LuminosityUnit = WATTS_IN_UNIT ^ 1> {
+            dynamic: new1o0<U>,
+            display: "{}",
+        }
+    }
+
+    define_quantity! {
+        Snap<L: LengthUnit = METERS_IN_UNIT ^ 1, T: TimeUnit = SECONDS_IN_UNIT ^ -4> {
+            dynamic: new1o4<L, T, T, T, T>,
+            display: "{}/{}^4",
+        }
+    }
+
+    #[test]
+    fn test_define_one_unit_quantity() {
+        let sun = Luminosity::<SolarLuminosities>::from(1);
+        assert_abs_diff_eq!(Luminosity::<Ergs>::from(&sun).f64(), 3.828e33);
+        assert_eq!(format!("{}", sun * scalar!(2)), "2L☉");
+        assert_abs_diff_eq!(sun + sun, Luminosity::<SolarLuminosities>::new(2.));
+        assert!(sun < Luminosity::<SolarLuminosities>::from(2));
+        assert_abs_diff_eq!((sun / Luminosity::<SolarLuminosities>::from(4)).f64(), 0.25);
+        let d = Luminosity::<SolarLuminosities>::from(sun.as_dyn());
+        assert_eq!(d, sun);
+    }
+
+    #[test]
+    fn test_define_two_unit_quantity() {
+        let s = Snap::<Meters, Seconds>::from(2);
+        assert_eq!(format!("{s}"), "2m/s^4");
+        assert_abs_diff_eq!(Snap::<Meters, Milliseconds>::from(&s).f64(), 2e-12);
+        assert_abs_diff_eq!(
+            Snap::<crate::Feet, Seconds>::from(&s).f64(),
+            f64::from(feet!(meters!(2)))
+        );
+        let j: Jerk<Meters, Seconds> = (s.as_dyn() * seconds!(3).as_dyn()).into();
+        assert_abs_diff_eq!(j, meters_per_second3!(6));
+        let ds: DynamicUnits = Snap::<Meters, Seconds>::new(1.).as_dyn();
+        assert_eq!(
+            Snap::<Meters, Seconds>::from(ds),
+            Snap::<Meters, Seconds>::from(1)
+        );
+    }
+}
@@ -1,10 +1,10 @@
 use crate::{
     impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
-    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, DynamicUnits,
-    LengthUnit, MassUnit,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Area, ArealDensity,
+    DynamicUnits, Length, LengthUnit, LinearDensity, Mass, MassUnit, Volume, VolumeUnit,
 };
 use ordered_float::OrderedFloat;
-use std::{fmt, fmt::Debug, marker::PhantomData};
+use std::{fmt, fmt::Debug, marker::PhantomData, ops::Mul};
 
 // mass / length^3
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
@@ -59,9 +59,69 @@ where
     }
 }
 
+impl<M, L> From<DynamicUnits> for Density<M, L>
+where
+    M: MassUnit,
+    L: LengthUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new1o3::<M, L, L, L>(0f64.into()));
+        Self {
+            v: f,
+            phantom_1: PhantomData,
+            phantom_2: PhantomData,
+        }
+    }
+}
+
+impl<M, L, V> Mul<Volume<V>> for Density<M, L>
+where
+    M: MassUnit,
+    L: LengthUnit,
+    V: VolumeUnit,
+{
+    type Output = Mass<M>;
+
+    fn mul(self, rhs: Volume<V>) -> Self::Output {
+        Mass::<M>::from(self.v.0 * Volume::<L>::from(&rhs).f64())
+    }
+}
+
+impl<M, L, LA> Mul<Area<LA>> for Density<M, L>
+where
+    M: MassUnit,
+    L: LengthUnit,
+    LA: LengthUnit,
+{
+    type Output = LinearDensity<M, L>;
+
+    fn mul(self, rhs: Area<LA>) -> Self::Output {
+        LinearDensity::<M, L>::from(self.v.0 * Area::<L>::from(&rhs).f64())
+    }
+}
+
+impl<M, L, LA> Mul<Length<LA>> for Density<M, L>
+where
+    M: MassUnit,
+    L: LengthUnit,
+    LA: LengthUnit,
+{
+    type Output = ArealDensity<M, L>;
+
+    fn mul(self, rhs: Length<LA>) -> Self::Output {
+        ArealDensity::<M, L>::from(self.v.0 * Length::<L>::from(&rhs).f64())
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::{kilograms_per_meter3, slugs_per_foot3};
+    use crate::{
+        feet, feet2, feet3, kilograms, kilograms_per_meter, kilograms_per_meter2,
+        kilograms_per_meter3, liters, meters, meters2, meters3, pounds_mass, pounds_per_feet,
+        pounds_per_feet2, pounds_per_feet3, slugs_per_foot3, us_gallons, Density, Kilograms,
+        Meters,
+    };
     use approx::assert_abs_diff_eq;
 
     #[test]
@@ -72,4 +132,53 @@ mod test {
         println!("{kg_p_m3}");
         assert_abs_diff_eq!(s_p_f3, slugs_per_foot3!(kg_p_m3), epsilon = 0.000_000_1);
     }
+
+    #[test]
+    fn test_density_volume_mass() {
+        let rho = kilograms_per_meter3!(800);
+        assert_abs_diff_eq!(rho * meters3!(2), kilograms!(1_600));
+        assert_abs_diff_eq!(meters3!(2) * rho, kilograms!(1_600));
+        assert_abs_diff_eq!(rho * liters!(500), kilograms!(400), epsilon = 1e-9);
+        assert_abs_diff_eq!(kilograms!(1_600) / meters3!(2), rho);
+        assert_abs_diff_eq!(kilograms!(1_600) / rho, meters3!(2));
+
+        // Mixed units resolve through the density's own units.
+        let fuel = pounds_per_feet3!(50) * us_gallons!(100);
+        assert_abs_diff_eq!(fuel, pounds_mass!(668.403), epsilon = 0.001);
+        assert_abs_diff_eq!(pounds_mass!(50) / feet3!(1), pounds_per_feet3!(50));
+        assert_abs_diff_eq!(
+            kilograms_per_meter3!(pounds_mass!(50) / liters!(100)),
+            kilograms_per_meter3!(226.796_185),
+            epsilon = 0.000_001
+        );
+        assert_abs_diff_eq!(
+            meters3!(kilograms!(400) / pounds_per_feet3!(50)),
+            meters3!(0.499_42),
+            epsilon = 0.000_1
+        );
+    }
+
+    #[test]
+    fn test_density_areal_linear() {
+        let rho = kilograms_per_meter3!(1_000);
+        assert_abs_diff_eq!(rho * meters!(0.01), kilograms_per_meter2!(10));
+        assert_abs_diff_eq!(rho * meters2!(0.5), kilograms_per_meter!(500));
+        assert_abs_diff_eq!(
+            pounds_per_feet2!(pounds_per_feet3!(60) * feet!(0.5)),
+            pounds_per_feet2!(30)
+        );
+        assert_abs_diff_eq!(
+            pounds_per_feet!(pounds_per_feet3!(60) * feet2!(0.5)),
+            pounds_per_feet!(30)
+        );
+        assert_abs_diff_eq!(kilograms!(10) / meters2!(2), kilograms_per_meter2!(5));
+        assert_abs_diff_eq!(kilograms!(10) / meters!(2), kilograms_per_meter!(5));
+    }
+
+    #[test]
+    fn test_density_dyn() {
+        let rho: Density<Kilograms, Meters> =
+            (kilograms!(10).as_dyn() / meters3!(2).as_dyn()).into();
+        assert_abs_diff_eq!(rho, kilograms_per_meter3!(5));
+    }
 }
@@ -89,6 +89,19 @@ impl DynamicUnits {
         }
     }
 
+    pub fn new0o1<D0>(v: OrderedFloat<f64>) -> Self
+    where
+        D0: Unit + 'static,
+    {
+        DynamicUnits {
+            v,
+            #[cfg(debug_assertions)]
+            numerator: HashBag::default(),
+            #[cfg(debug_assertions)]
+            denominator: HashBag::from_iter([TypeId::of::<D0>()]),
+        }
+    }
+
     pub fn new1o0<N0>(v: OrderedFloat<f64>) -> Self
     where
         N0: Unit + 'static,
@@ -151,6 +164,28 @@ impl DynamicUnits {
         }
     }
 
+    pub fn new1o4<N0, D0, D1, D2, D3>(v: OrderedFloat<f64>) -> Self
+    where
+        N0: Unit + 'static,
+        D0: Unit + 'static,
+        D1: Unit + 'static,
+        D2: Unit + 'static,
+        D3: Unit + 'static,
+    {
+        DynamicUnits {
+            v,
+            #[cfg(debug_assertions)]
+            numerator: HashBag::from_iter([TypeId::of::<N0>()]),
+            #[cfg(debug_assertions)]
+            denominator: HashBag::from_iter([
+                TypeId::of::<D0>(),
+                TypeId::of::<D1>(),
+                TypeId::of::<D2>(),
+                TypeId::of::<D3>(),
+            ]),
+        }
+    }
+
     pub fn new2o0<N0, N1>(v: OrderedFloat<f64>) -> Self
     where
         N0: Unit + 'static,
@@ -165,6 +200,21 @@ impl DynamicUnits {
         }
     }
 
+    pub fn new2o1<N0, N1, D0>(v: OrderedFloat<f64>) -> Self
+    where
+        N0: Unit + 'static,
+        N1: Unit + 'static,
+        D0: Unit + 'static,
+    {
+        DynamicUnits {
+            v,
+            #[cfg(debug_assertions)]
+            numerator: HashBag::from_iter([TypeId::of::<N0>(), TypeId::of::<N1>()]),
+            #[cfg(debug_assertions)]
+            denominator: HashBag::from_iter([TypeId::of::<D0>()]),
+        }
+    }
+
     pub fn new2o2<N0, N1, D0, D1>(v: OrderedFloat<f64>) -> Self
     where
         N0: Unit + 'static,
@@ -181,6 +231,27 @@ impl DynamicUnits {
         }
     }
 
+    pub fn new2o3<N0, N1, D0, D1, D2>(v: OrderedFloat<f64>) -> Self
+    where
+        N0: Unit + 'static,
+        N1: Unit + 'static,
+        D0: Unit + 'static,
+        D1: Unit + 'static,
+        D2: Unit + 'static,
+    {
+        DynamicUnits {
+            v,
+            #[cfg(debug_assertions)]
+            numerator: HashBag::from_iter([TypeId::of::<N0>(), TypeId::of::<N1>()]),
+            #[cfg(debug_assertions)]
+            denominator: HashBag::from_iter([
+                TypeId::of::<D0>(),
+                TypeId::of::<D1>(),
+                TypeId::of::<D2>(),
+            ]),
+        }
+    }
+
     pub fn new3o0<N0, N1, N2>(v: OrderedFloat<f64>) -> Self
     where
         N0: Unit + 'static,
@@ -200,6 +271,26 @@ impl DynamicUnits {
         }
     }
 
+    pub fn new3o1<N0, N1, N2, D0>(v: OrderedFloat<f64>) -> Self
+    where
+        N0: Unit + 'static,
+        N1: Unit + 'static,
+        N2: Unit + 'static,
+        D0: Unit + 'static,
+    {
+        DynamicUnits {
+            v,
+            #[cfg(debug_assertions)]
+            numerator: HashBag::from_iter([
+                TypeId::of::<N0>(),
+                TypeId::of::<N1>(),
+                TypeId::of::<N2>(),
+            ]),
+            #[cfg(debug_assertions)]
+            denominator: HashBag::from_iter([TypeId::of::<D0>()]),
+        }
+    }
+
     pub fn new3o2<N0, N1, N2, D0, D1>(v: OrderedFloat<f64>) -> Self
     where
         N0: Unit + 'static,
@@ -220,6 +311,32 @@ impl DynamicUnits {
             denominator: HashBag::from_iter([TypeId::of::<D0>(), TypeId::of::<D1>()]),
         }
     }
+
+    pub fn new3o3<N0, N1, N2, D0, D1, D2>(v: OrderedFloat<f64>) -> Self
+    where
+        N0: Unit + 'static,
+        N1: Unit + 'static,
+        N2: Unit + 'static,
+        D0: Unit + 'static,
+        D1: Unit + 'static,
+        D2: Unit + 'static,
+    {
+        DynamicUnits {
+            v,
+            #[cfg(debug_assertions)]
+            numerator: HashBag::from_iter([
+                TypeId::of::<N0>(),
+                TypeId::of::<N1>(),
+                TypeId::of::<N2>(),
+            ]),
+            #[cfg(debug_assertions)]
+            denominator: HashBag::from_iter([
+                TypeId::of::<D0>(),
+                TypeId::of::<D1>(),
+                TypeId::of::<D2>(),
+            ]),
+        }
+    }
 }
 
 impl Add<DynamicUnits> for DynamicUnits {
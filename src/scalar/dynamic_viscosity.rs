@@ -0,0 +1,168 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Density, DynamicUnits,
+    Kilograms, KinematicViscosity, Length, LengthUnit, MassUnit, Meters, PascalSeconds, Scalar,
+    Seconds, SquareMetersPerSecond, TimeUnit, Unit, Velocity,
+};
+use ordered_float::OrderedFloat;
+use std::{fmt, fmt::Debug, marker::PhantomData, ops::Div};
+
+pub trait DynamicViscosityUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const PASCAL_SECONDS_IN_UNIT: f64;
+}
+
+// pressure * time: mass / (length * time)
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct DynamicViscosity<Unit: DynamicViscosityUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_quantity_ops!(DynamicViscosity<A>, DynamicViscosityUnit);
+supports_shift_ops!(
+    DynamicViscosity<A1>,
+    DynamicViscosity<A2>,
+    DynamicViscosityUnit
+);
+supports_scalar_ops!(DynamicViscosity<A>, DynamicViscosityUnit);
+supports_cancellation!(
+    DynamicViscosity<A1>,
+    DynamicViscosity<A2>,
+    DynamicViscosityUnit
+);
+supports_absdiffeq!(DynamicViscosity<A>, DynamicViscosityUnit);
+supports_value_type_conversion!(
+    DynamicViscosity<A>,
+    DynamicViscosityUnit,
+    impl_value_type_conversions
+);
+
+impl<U> DynamicViscosity<U>
+where
+    U: DynamicViscosityUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new1o2::<Kilograms, Meters, Seconds>(self.v * U::PASCAL_SECONDS_IN_UNIT)
+    }
+}
+
+impl<Unit> fmt::Display for DynamicViscosity<Unit>
+where
+    Unit: DynamicViscosityUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a DynamicViscosity<UnitA>> for DynamicViscosity<UnitB>
+where
+    UnitA: DynamicViscosityUnit,
+    UnitB: DynamicViscosityUnit,
+{
+    fn from(v: &'a DynamicViscosity<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::PASCAL_SECONDS_IN_UNIT / UnitB::PASCAL_SECONDS_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> From<DynamicUnits> for DynamicViscosity<U>
+where
+    U: DynamicViscosityUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new1o2::<Kilograms, Meters, Seconds>(
+            0f64.into(),
+        ));
+        Self {
+            v: f / U::PASCAL_SECONDS_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U, M, L> Div<Density<M, L>> for DynamicViscosity<U>
+where
+    U: DynamicViscosityUnit,
+    M: MassUnit,
+    L: LengthUnit,
+{
+    type Output = KinematicViscosity<SquareMetersPerSecond>;
+
+    fn div(self, rhs: Density<M, L>) -> Self::Output {
+        let mu = DynamicViscosity::<PascalSeconds>::from(&self);
+        let rho = Density::<Kilograms, Meters>::from(&rhs);
+        Self::Output::from(mu.f64() / rho.f64())
+    }
+}
+
+/// Reynolds number, the ratio of inertial to viscous forces in a flow:
+/// density * velocity * characteristic length / dynamic viscosity.
+pub fn reynolds_number<M, LD, LV, T, L, U>(
+    density: Density<M, LD>,
+    velocity: Velocity<LV, T>,
+    length: Length<L>,
+    viscosity: DynamicViscosity<U>,
+) -> Scalar
+where
+    M: MassUnit,
+    LD: LengthUnit,
+    LV: LengthUnit,
+    T: TimeUnit,
+    L: LengthUnit,
+    U: DynamicViscosityUnit,
+{
+    let rho = Density::<Kilograms, Meters>::from(&density);
+    let v = Velocity::<Meters, Seconds>::from(&velocity);
+    let l = Length::<Meters>::from(&length);
+    let mu = DynamicViscosity::<PascalSeconds>::from(&viscosity);
+    Scalar::from(rho.f64() * v.f64() * l.f64() / mu.f64())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        centipoise, kilograms_per_meter3, meters, meters2_per_second, meters_per_second,
+        pascal_seconds, poise, reynolds_number, scalar, DynamicViscosity, PascalSeconds,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_dynamic_viscosity() {
+        let mu = poise!(1);
+        println!("{mu}");
+        assert_abs_diff_eq!(pascal_seconds!(mu), pascal_seconds!(0.1));
+        assert_abs_diff_eq!(
+            centipoise!(pascal_seconds!(0.001)),
+            centipoise!(1),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_kinematic_from_dynamic() {
+        // Water at about 20C
+        let nu = pascal_seconds!(0.001) / kilograms_per_meter3!(1_000);
+        assert_abs_diff_eq!(nu, meters2_per_second!(0.000_001), epsilon = 1e-15);
+    }
+
+    #[test]
+    fn test_reynolds_number() {
+        let re = reynolds_number(
+            kilograms_per_meter3!(1_000),
+            meters_per_second!(2),
+            meters!(0.05),
+            pascal_seconds!(0.001),
+        );
+        assert_abs_diff_eq!(re, scalar!(100_000), epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_dynamic_viscosity_dyn() {
+        let mu: DynamicViscosity<PascalSeconds> = pascal_seconds!(2).as_dyn().into();
+        assert_abs_diff_eq!(mu, pascal_seconds!(2));
+    }
+}
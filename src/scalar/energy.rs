@@ -0,0 +1,153 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, DynamicUnits, Joules,
+    Kilograms, Meters, Power, Scalar, Seconds, Time, TimeUnit, Unit, Watts,
+};
+use ordered_float::OrderedFloat;
+use std::{fmt, fmt::Debug, marker::PhantomData, ops::Div};
+
+pub trait EnergyUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const JOULES_IN_UNIT: f64;
+}
+
+/// mass * length * length / time / time
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Energy<Unit: EnergyUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_absdiffeq!(Energy<A>, EnergyUnit);
+supports_quantity_ops!(Energy<A>, EnergyUnit);
+supports_scalar_ops!(Energy<A>, EnergyUnit);
+supports_cancellation!(Energy<A1>, Energy<A2>, EnergyUnit);
+supports_shift_ops!(Energy<A1>, Energy<A2>, EnergyUnit);
+supports_value_type_conversion!(Energy<A>, EnergyUnit, impl_value_type_conversions);
+
+impl<E> Energy<E>
+where
+    E: EnergyUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new3o2::<Kilograms, Meters, Meters, Seconds, Seconds>(
+            self.v * E::JOULES_IN_UNIT,
+        )
+    }
+}
+
+impl<Unit> fmt::Display for Energy<Unit>
+where
+    Unit: EnergyUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a Energy<UnitA>> for Energy<UnitB>
+where
+    UnitA: EnergyUnit,
+    UnitB: EnergyUnit,
+{
+    fn from(v: &'a Energy<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::JOULES_IN_UNIT / UnitB::JOULES_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<E> From<DynamicUnits> for Energy<E>
+where
+    E: EnergyUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new3o2::<
+            Kilograms,
+            Meters,
+            Meters,
+            Seconds,
+            Seconds,
+        >(0f64.into()));
+        Self {
+            v: f / E::JOULES_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<E, T> Div<Time<T>> for Energy<E>
+where
+    E: EnergyUnit,
+    T: TimeUnit,
+{
+    type Output = Power<Watts>;
+
+    fn div(self, rhs: Time<T>) -> Self::Output {
+        let energy = Energy::<Joules>::from(&self);
+        let time = Time::<Seconds>::from(&rhs);
+        Self::Output::from(energy.f64() / time.f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        btu, foot_pounds, joules, kilocalories, kilograms, kilowatt_hours, meters, meters2,
+        meters_per_second, newtons, pascals, pounds_force, scalar, Energy, FootPounds, Joules,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_energy() {
+        let j = joules!(1_055.055_852_62_f64);
+        println!("{j}");
+        println!("{}", btu!(j));
+        assert_abs_diff_eq!(btu!(j), btu!(1), epsilon = 0.000_000_1);
+        assert_abs_diff_eq!(joules!(kilowatt_hours!(1)), joules!(3_600_000));
+        assert_abs_diff_eq!(joules!(kilocalories!(1)), joules!(4_184));
+        assert_abs_diff_eq!(
+            foot_pounds!(joules!(1)),
+            foot_pounds!(0.737_562),
+            epsilon = 0.000_001
+        );
+    }
+
+    #[test]
+    fn test_energy_scalar() {
+        assert_abs_diff_eq!(joules!(2) * scalar!(2), joules!(4));
+    }
+
+    #[test]
+    fn test_work() {
+        let w = newtons!(10).work_over(meters!(3));
+        assert_abs_diff_eq!(w, joules!(30));
+        let w = pounds_force!(1).work_over(meters!(1));
+        assert_abs_diff_eq!(foot_pounds!(w), foot_pounds!(3.280_84), epsilon = 0.000_01);
+    }
+
+    #[test]
+    fn test_kinetic_energy() {
+        let v = meters_per_second!(4);
+        let ke = scalar!(0.5) * kilograms!(2) * (v * v);
+        assert_abs_diff_eq!(ke, joules!(16));
+    }
+
+    #[test]
+    fn test_pressure_volume_work() {
+        let w = pascals!(100) * (meters2!(2) * meters!(3));
+        assert_abs_diff_eq!(w, joules!(600));
+    }
+
+    #[test]
+    fn test_energy_dyn() {
+        let e: Energy<Joules> = (newtons!(2).as_dyn() * meters!(3).as_dyn()).into();
+        assert_abs_diff_eq!(e, joules!(6));
+        let e: Energy<Joules> = kilowatt_hours!(1).as_dyn().into();
+        assert_abs_diff_eq!(e, joules!(3_600_000));
+        let e: Energy<FootPounds> = btu!(1).as_dyn().into();
+        assert_abs_diff_eq!(e, foot_pounds!(btu!(1)), epsilon = 1e-9);
+        assert_abs_diff_eq!(e.f64(), 778.169, epsilon = 1e-3);
+    }
+}
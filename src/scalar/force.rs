@@ -1,7 +1,9 @@
 use crate::{
     impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
-    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Acceleration,
-    DynamicUnits, Length, LengthUnit, Mass, MassUnit, Scalar, TimeUnit, Torque, Unit,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Acceleration, Area,
+    DynamicUnits, Energy, Impulse, Joules, Kilograms, Length, LengthUnit, Mass, MassUnit, Meters,
+    Newtons, Pascals, Power, Pressure, Scalar, Seconds, Time, TimeUnit, Torque, Unit, Velocity,
+    Watts,
 };
 use ordered_float::OrderedFloat;
 use std::{
@@ -14,6 +16,9 @@ use std::{
 pub trait ForceUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
     const NEWTONS_IN_UNIT: f64;
 
+    // The mass, length, and time units this force is dimensionally built from. These
+    // need not multiply out to the unit (kgf, kN), so values always convert through
+    // NEWTONS_IN_UNIT rather than through these.
     type UnitMass: MassUnit;
     type UnitLength: LengthUnit;
     type UnitTime: TimeUnit;
@@ -37,7 +42,18 @@ where
     F: ForceUnit,
 {
     pub fn as_dyn(&self) -> DynamicUnits {
-        DynamicUnits::new2o2::<F::UnitMass, F::UnitLength, F::UnitTime, F::UnitTime>(self.v)
+        DynamicUnits::new2o2::<Kilograms, Meters, Seconds, Seconds>(self.v * F::NEWTONS_IN_UNIT)
+    }
+
+    /// Work done by this force acting along the given distance. This has the same
+    /// dimensions as force * length, but is energy rather than torque.
+    pub fn work_over<L>(&self, distance: Length<L>) -> Energy<Joules>
+    where
+        L: LengthUnit,
+    {
+        let force = Force::<Newtons>::from(self);
+        let distance = Length::<Meters>::from(&distance);
+        Energy::<Joules>::from(force.f64() * distance.f64())
     }
 }
 
@@ -70,29 +86,41 @@ where
 {
     fn from(v: DynamicUnits) -> Self {
         let f = v.ordered_float();
-        v.assert_units_equal(DynamicUnits::new2o2::<
-            F::UnitMass,
-            F::UnitLength,
-            F::UnitTime,
-            F::UnitTime,
-        >(0f64.into()));
+        v.assert_units_equal(DynamicUnits::new2o2::<Kilograms, Meters, Seconds, Seconds>(
+            0f64.into(),
+        ));
         Self {
-            v: f,
+            v: f / F::NEWTONS_IN_UNIT,
             phantom_1: PhantomData,
         }
     }
 }
 
+impl<F, L> Div<Area<L>> for Force<F>
+where
+    F: ForceUnit,
+    L: LengthUnit,
+{
+    type Output = Pressure<Pascals>;
+
+    fn div(self, rhs: Area<L>) -> Self::Output {
+        let force = Force::<Newtons>::from(&self);
+        let area = Area::<Meters>::from(&rhs);
+        Self::Output::from(force.f64() / area.f64())
+    }
+}
+
 impl<F, M> Div<Mass<M>> for Force<F>
 where
     F: ForceUnit, // kg*m/s^2
     M: MassUnit,
 {
-    type Output = Acceleration<F::UnitLength, F::UnitTime>;
+    type Output = Acceleration<Meters, Seconds>;
 
     fn div(self, rhs: Mass<M>) -> Self::Output {
-        let mass = Mass::<F::UnitMass>::from(&rhs);
-        Self::Output::from(self.v.0 / mass.f64())
+        let force = Force::<Newtons>::from(&self);
+        let mass = Mass::<Kilograms>::from(&rhs);
+        Self::Output::from(force.f64() / mass.f64())
     }
 }
 
@@ -102,11 +130,12 @@ where
     L: LengthUnit,
     T: TimeUnit,
 {
-    type Output = Mass<F::UnitMass>;
+    type Output = Mass<Kilograms>;
 
     fn div(self, rhs: Acceleration<L, T>) -> Self::Output {
-        let acc = Acceleration::<F::UnitLength, F::UnitTime>::from(&rhs);
-        Self::Output::from(self.v.0 / acc.f64())
+        let force = Force::<Newtons>::from(&self);
+        let acc = Acceleration::<Meters, Seconds>::from(&rhs);
+        Self::Output::from(force.f64() / acc.f64())
     }
 }
 
@@ -122,9 +151,42 @@ where
     }
 }
 
+impl<F, L, T> Mul<Velocity<L, T>> for Force<F>
+where
+    F: ForceUnit,
+    L: LengthUnit,
+    T: TimeUnit,
+{
+    type Output = Power<Watts>;
+
+    fn mul(self, rhs: Velocity<L, T>) -> Self::Output {
+        let force = Force::<Newtons>::from(&self);
+        let velocity = Velocity::<Meters, Seconds>::from(&rhs);
+        Self::Output::from(force.f64() * velocity.f64())
+    }
+}
+
+impl<F, T> Mul<Time<T>> for Force<F>
+where
+    F: ForceUnit,
+    T: TimeUnit,
+{
+    type Output = Impulse<Kilograms, Meters, Seconds>;
+
+    fn mul(self, rhs: Time<T>) -> Self::Output {
+        let force = Force::<Newtons>::from(&self);
+        let time = Time::<Seconds>::from(&rhs);
+        Self::Output::from(force.f64() * time.f64())
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::{kilograms, newtons, pounds_force, pounds_mass, scalar};
+    use crate::{
+        dynes, feet_per_second2, kgf, kilograms, kilograms_force, kilonewtons, meters_per_second2,
+        newtons, pdl, poundals, pounds_force, pounds_mass, scalar, Dynes, Force, KilogramsForce,
+        Newtons, Poundals, PoundsForce,
+    };
     use approx::assert_abs_diff_eq;
 
     #[test]
@@ -149,4 +211,95 @@ mod test {
         assert!(imperial_units_should_be_a_capital_offense < 1_f64);
         assert!((newtons!(lbf) / kilograms!(lb)).f64() / 9.80665 > 1_f64);
     }
+
+    #[test]
+    fn test_prefixed_force_to_acceleration_and_mass() {
+        assert_abs_diff_eq!(
+            kilonewtons!(1) / kilograms!(1),
+            meters_per_second2!(1_000),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(
+            kilonewtons!(1) / meters_per_second2!(1),
+            kilograms!(1_000),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(
+            kilonewtons!(2) / feet_per_second2!(meters_per_second2!(4)),
+            kilograms!(500),
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_force_units() {
+        assert_abs_diff_eq!(
+            pounds_force!(pdl!(32.174_048_556)),
+            pounds_force!(1),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(
+            poundals!(pounds_force!(1)).f64(),
+            32.174_048_556,
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(newtons!(kgf!(1)), newtons!(9.806_65));
+        assert_abs_diff_eq!(
+            pounds_force!(kilograms_force!(0.453_592_37)),
+            pounds_force!(1)
+        );
+        assert_abs_diff_eq!(dynes!(newtons!(1)), dynes!(100_000), epsilon = 1e-9);
+        assert_abs_diff_eq!(
+            kilonewtons!(kgf!(1_000)),
+            kilonewtons!(9.806_65),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_force_units_dyn() {
+        let f: Force<Newtons> = kgf!(1).as_dyn().into();
+        assert_abs_diff_eq!(f, newtons!(9.806_65));
+        let f: Force<KilogramsForce> = f.as_dyn().into();
+        assert_abs_diff_eq!(f, kgf!(1));
+        let f: Force<Poundals> = pounds_force!(1).as_dyn().into();
+        assert_abs_diff_eq!(f.f64(), 32.174_048_556, epsilon = 1e-9);
+        let f: Force<PoundsForce> = f.as_dyn().into();
+        assert_abs_diff_eq!(f, pounds_force!(1), epsilon = 1e-12);
+        let f: Force<Dynes> = newtons!(1).as_dyn().into();
+        assert_abs_diff_eq!(f, dynes!(100_000), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_kilograms_force_to_acceleration_and_mass() {
+        assert_abs_diff_eq!(
+            kgf!(1) / kilograms!(1),
+            meters_per_second2!(9.806_65),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            kgf!(1) / meters_per_second2!(1),
+            kilograms!(9.806_65),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            kgf!(3) / meters_per_second2!(9.806_65),
+            kilograms!(3),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_poundals_from_mass() {
+        // A poundal accelerates a pound of mass at one foot per second squared.
+        assert_abs_diff_eq!(
+            pdl!(pounds_mass!(1) * feet_per_second2!(1)),
+            pdl!(1),
+            epsilon = 1e-12
+        );
+        let f: Force<Poundals> = (kilograms!(pounds_mass!(2)).as_dyn()
+            * meters_per_second2!(feet_per_second2!(3)).as_dyn())
+        .into();
+        assert_abs_diff_eq!(f, pdl!(6), epsilon = 1e-12);
+    }
 }
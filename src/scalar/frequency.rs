@@ -0,0 +1,182 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, AngleUnit,
+    AngularVelocity, DynamicUnits, Hertz, Radians, Scalar, Seconds, Time, TimeUnit, Unit,
+};
+use ordered_float::OrderedFloat;
+use std::{
+    f64::consts::TAU,
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
+
+pub trait FrequencyUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const HERTZ_IN_UNIT: f64;
+}
+
+/// cycles / time
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Frequency<Unit: FrequencyUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_absdiffeq!(Frequency<A>, FrequencyUnit);
+supports_quantity_ops!(Frequency<A>, FrequencyUnit);
+supports_scalar_ops!(Frequency<A>, FrequencyUnit);
+supports_cancellation!(Frequency<A1>, Frequency<A2>, FrequencyUnit);
+supports_shift_ops!(Frequency<A1>, Frequency<A2>, FrequencyUnit);
+supports_value_type_conversion!(Frequency<A>, FrequencyUnit, impl_value_type_conversions);
+
+impl<F> Frequency<F>
+where
+    F: FrequencyUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new0o1::<Seconds>(self.v * F::HERTZ_IN_UNIT)
+    }
+}
+
+impl<Unit> fmt::Display for Frequency<Unit>
+where
+    Unit: FrequencyUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a Frequency<UnitA>> for Frequency<UnitB>
+where
+    UnitA: FrequencyUnit,
+    UnitB: FrequencyUnit,
+{
+    fn from(v: &'a Frequency<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::HERTZ_IN_UNIT / UnitB::HERTZ_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<F> From<DynamicUnits> for Frequency<F>
+where
+    F: FrequencyUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new0o1::<Seconds>(0f64.into()));
+        Self {
+            v: f / F::HERTZ_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+// One cycle is one full turn, or 2π radians.
+impl<'a, F, A, T> From<&'a Frequency<F>> for AngularVelocity<A, T>
+where
+    F: FrequencyUnit,
+    A: AngleUnit,
+    T: TimeUnit,
+{
+    fn from(v: &'a Frequency<F>) -> Self {
+        let hz = Frequency::<Hertz>::from(v);
+        AngularVelocity::<A, T>::from(&AngularVelocity::<Radians, Seconds>::from(hz.f64() * TAU))
+    }
+}
+
+impl<'a, A, T, F> From<&'a AngularVelocity<A, T>> for Frequency<F>
+where
+    A: AngleUnit,
+    T: TimeUnit,
+    F: FrequencyUnit,
+{
+    fn from(v: &'a AngularVelocity<A, T>) -> Self {
+        let rad_per_s = AngularVelocity::<Radians, Seconds>::from(v);
+        Frequency::<F>::from(&Frequency::<Hertz>::from(rad_per_s.f64() / TAU))
+    }
+}
+
+impl<T> Div<Time<T>> for Scalar
+where
+    T: TimeUnit,
+{
+    type Output = Frequency<Hertz>;
+
+    fn div(self, rhs: Time<T>) -> Self::Output {
+        Self::Output::from(self.into_inner() / Time::<Seconds>::from(&rhs).f64())
+    }
+}
+
+impl<F, T> Mul<Time<T>> for Frequency<F>
+where
+    F: FrequencyUnit,
+    T: TimeUnit,
+{
+    type Output = Scalar;
+
+    fn mul(self, rhs: Time<T>) -> Self::Output {
+        let hz = Frequency::<Hertz>::from(&self);
+        Scalar::from(hz.f64() * Time::<Seconds>::from(&rhs).f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        cycles_per_minute, degrees_per_second, hertz, kilohertz, radians_per_second, rpm, scalar,
+        seconds, Frequency, Hertz, Rpm,
+    };
+    use approx::assert_abs_diff_eq;
+    use std::f64::consts::TAU;
+
+    #[test]
+    fn test_frequency() {
+        let r = rpm!(2_400);
+        println!("{r}");
+        println!("{}", hertz!(r));
+        assert_abs_diff_eq!(hertz!(r), hertz!(40));
+        assert_abs_diff_eq!(cycles_per_minute!(r), cycles_per_minute!(2_400));
+        assert_abs_diff_eq!(kilohertz!(hertz!(1_500)), kilohertz!(1.5));
+    }
+
+    #[test]
+    fn test_frequency_scalar() {
+        assert_abs_diff_eq!(hertz!(2) * scalar!(2), hertz!(4));
+    }
+
+    #[test]
+    fn test_frequency_time() {
+        assert_abs_diff_eq!(scalar!(10) / seconds!(2), hertz!(5));
+        assert_abs_diff_eq!(hertz!(5) * seconds!(2), scalar!(10));
+        assert_abs_diff_eq!(rpm!(60) * seconds!(30), scalar!(30));
+    }
+
+    #[test]
+    fn test_frequency_angular_velocity() {
+        assert_abs_diff_eq!(radians_per_second!(hertz!(1)), radians_per_second!(TAU));
+        assert_abs_diff_eq!(
+            degrees_per_second!(rpm!(60)),
+            degrees_per_second!(360),
+            epsilon = 0.000_000_001
+        );
+        assert_abs_diff_eq!(rpm!(radians_per_second!(TAU)), rpm!(60));
+        let f = rpm!(1_234.5);
+        assert_abs_diff_eq!(rpm!(radians_per_second!(f)), f, epsilon = 0.000_000_001);
+    }
+
+    #[test]
+    fn test_frequency_dyn() {
+        let f: Frequency<Hertz> = (scalar!(4).as_dyn() / seconds!(2).as_dyn()).into();
+        assert_abs_diff_eq!(f, hertz!(2));
+        let f: Frequency<Hertz> = rpm!(60).as_dyn().into();
+        assert_abs_diff_eq!(f, hertz!(1));
+        let f: Frequency<Hertz> = kilohertz!(1).as_dyn().into();
+        assert_abs_diff_eq!(f, hertz!(1_000));
+        let f: Frequency<Rpm> = (scalar!(3) / seconds!(1)).as_dyn().into();
+        assert_abs_diff_eq!(f, rpm!(180));
+    }
+}
@@ -1,5 +1,53 @@
 #[macro_export]
 macro_rules! supports_value_type_conversion {
+    ($TypeName:ty, $UnitA:path, $UnitB:path, $UnitC:path, $it:tt) => {
+        $it!(f64, $TypeName, $UnitA, $UnitB, $UnitC);
+        $it!(f32, $TypeName, $UnitA, $UnitB, $UnitC);
+        $it!(isize, $TypeName, $UnitA, $UnitB, $UnitC);
+        $it!(i64, $TypeName, $UnitA, $UnitB, $UnitC);
+        $it!(i32, $TypeName, $UnitA, $UnitB, $UnitC);
+        $it!(i16, $TypeName, $UnitA, $UnitB, $UnitC);
+        $it!(i8, $TypeName, $UnitA, $UnitB, $UnitC);
+        $it!(u32, $TypeName, $UnitA, $UnitB, $UnitC);
+        $it!(u16, $TypeName, $UnitA, $UnitB, $UnitC);
+        $it!(u8, $TypeName, $UnitA, $UnitB, $UnitC);
+
+        impl<A, B, C> $TypeName
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            pub fn f64(self) -> f64 {
+                f64::from(self)
+            }
+
+            pub fn of64(self) -> OrderedFloat<f64> {
+                OrderedFloat(f64::from(self))
+            }
+
+            pub fn f32(self) -> f32 {
+                f32::from(self)
+            }
+
+            pub fn of32(self) -> OrderedFloat<f32> {
+                OrderedFloat(f32::from(self))
+            }
+
+            pub fn is_nan(&self) -> bool {
+                self.v.0.is_nan()
+            }
+
+            pub fn is_infinite(&self) -> bool {
+                self.v.0.is_infinite()
+            }
+
+            pub fn is_finite(&self) -> bool {
+                self.v.0.is_finite()
+            }
+        }
+    };
+
     ($TypeName:ty, $UnitA:path, $UnitB:path, $it:tt) => {
         $it!(f64, $TypeName, $UnitA, $UnitB);
         $it!(f32, $TypeName, $UnitA, $UnitB);
@@ -109,6 +157,51 @@ macro_rules! supports_value_type_conversion {
 
 #[macro_export]
 macro_rules! impl_value_type_conversions {
+    (f64, $TypeName:ty, $UnitA:path, $UnitB:path, $UnitC:path) => {
+        impl<A, B, C> From<f64> for $TypeName
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            fn from(v: f64) -> Self {
+                Self {
+                    v: OrderedFloat(v),
+                    phantom_1: PhantomData,
+                    phantom_2: PhantomData,
+                    phantom_3: PhantomData,
+                }
+            }
+        }
+
+        impl<A, B, C> From<&f64> for $TypeName
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            fn from(v: &f64) -> Self {
+                Self {
+                    v: OrderedFloat(*v),
+                    phantom_1: PhantomData,
+                    phantom_2: PhantomData,
+                    phantom_3: PhantomData,
+                }
+            }
+        }
+
+        impl<A, B, C> From<$TypeName> for f64
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            fn from(v: $TypeName) -> f64 {
+                v.v.0
+            }
+        }
+    };
+
     (f64, $TypeName:ty, $UnitA:path, $UnitB:path) => {
         impl<A, B> From<f64> for $TypeName
         where
@@ -184,6 +277,51 @@ macro_rules! impl_value_type_conversions {
         }
     };
 
+    (f32, $TypeName:ty, $UnitA:path, $UnitB:path, $UnitC:path) => {
+        impl<A, B, C> From<f32> for $TypeName
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            fn from(v: f32) -> Self {
+                Self {
+                    v: OrderedFloat(v as f64),
+                    phantom_1: PhantomData,
+                    phantom_2: PhantomData,
+                    phantom_3: PhantomData,
+                }
+            }
+        }
+
+        impl<A, B, C> From<&f32> for $TypeName
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            fn from(v: &f32) -> Self {
+                Self {
+                    v: OrderedFloat(*v as f64),
+                    phantom_1: PhantomData,
+                    phantom_2: PhantomData,
+                    phantom_3: PhantomData,
+                }
+            }
+        }
+
+        impl<A, B, C> From<$TypeName> for f32
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            fn from(v: $TypeName) -> f32 {
+                v.v.0 as f32
+            }
+        }
+    };
+
     (f32, $TypeName:ty, $UnitA:path, $UnitB:path) => {
         impl<A, B> From<f32> for $TypeName
         where
@@ -259,6 +397,51 @@ macro_rules! impl_value_type_conversions {
         }
     };
 
+    ($Num:ty, $TypeName:ty, $UnitA:path, $UnitB:path, $UnitC:path) => {
+        impl<A, B, C> From<$Num> for $TypeName
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            fn from(v: $Num) -> Self {
+                Self {
+                    v: OrderedFloat(v as f64),
+                    phantom_1: PhantomData,
+                    phantom_2: PhantomData,
+                    phantom_3: PhantomData,
+                }
+            }
+        }
+
+        impl<A, B, C> From<&$Num> for $TypeName
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            fn from(v: &$Num) -> Self {
+                Self {
+                    v: OrderedFloat(*v as f64),
+                    phantom_1: PhantomData,
+                    phantom_2: PhantomData,
+                    phantom_3: PhantomData,
+                }
+            }
+        }
+
+        impl<A, B, C> From<$TypeName> for $Num
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            fn from(v: $TypeName) -> $Num {
+                v.v.0.round() as $Num
+            }
+        }
+    };
+
     ($Num:ty, $TypeName:ty, $UnitA:path, $UnitB:path) => {
         impl<A, B> From<$Num> for $TypeName
         where
@@ -337,6 +520,46 @@ macro_rules! impl_value_type_conversions {
 
 #[macro_export]
 macro_rules! supports_absdiffeq {
+    ($TypeName:ty, $UnitA:path, $UnitB:path, $UnitC:path) => {
+        impl<A, B, C> $crate::approx::AbsDiffEq for $TypeName
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            type Epsilon = f64;
+
+            fn default_epsilon() -> Self::Epsilon {
+                f64::default_epsilon()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+                self.v.0.abs_diff_eq(&other.v.0, epsilon)
+            }
+        }
+
+        impl<A, B, C> $crate::approx::RelativeEq for $TypeName
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            fn default_max_relative() -> Self::Epsilon {
+                use $crate::approx::AbsDiffEq;
+                f64::default_epsilon()
+            }
+
+            fn relative_eq(
+                &self,
+                other: &Self,
+                epsilon: Self::Epsilon,
+                max_relative: Self::Epsilon,
+            ) -> bool {
+                self.v.0.relative_eq(&other.v.0, epsilon, max_relative)
+            }
+        }
+    };
+
     ($TypeName:ty, $UnitA:path, $UnitB:path) => {
         impl<A, B> $crate::approx::AbsDiffEq for $TypeName
         where
@@ -414,6 +637,123 @@ macro_rules! supports_absdiffeq {
 
 #[macro_export]
 macro_rules! supports_scalar_ops {
+    ($TypeName:ty, $UnitA:path, $UnitB:path, $UnitC:path) => {
+        impl<A, B, C> std::ops::Mul<$crate::Scalar> for $TypeName
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            type Output = $TypeName;
+
+            fn mul(self, s: $crate::Scalar) -> Self::Output {
+                use $crate::Quantity;
+                Self {
+                    v: self.v * s.f64(),
+                    phantom_1: PhantomData,
+                    phantom_2: PhantomData,
+                    phantom_3: PhantomData,
+                }
+            }
+        }
+
+        impl<A, B, C> std::ops::Mul<$TypeName> for $crate::Scalar
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            type Output = $TypeName;
+
+            fn mul(self, other: $TypeName) -> Self::Output {
+                <$TypeName>::from(self.into_inner() * other.f64())
+            }
+        }
+
+        impl<A, B, C> std::ops::MulAssign<$crate::Scalar> for $TypeName
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            fn mul_assign(&mut self, s: $crate::Scalar) {
+                use $crate::Quantity;
+                self.v *= s.f64();
+            }
+        }
+
+        impl<A, B, C> std::ops::Mul<$TypeName> for $crate::glam::DVec3
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            type Output = $crate::V3<$TypeName>;
+
+            fn mul(self, other: $TypeName) -> Self::Output {
+                $crate::V3::<$TypeName>::new_dvec3(self * other.f64())
+            }
+        }
+
+        impl<A, B, C> std::ops::Mul<$crate::glam::DVec3> for $TypeName
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            type Output = $crate::V3<$TypeName>;
+
+            fn mul(self, other: $crate::glam::DVec3) -> Self::Output {
+                $crate::V3::<$TypeName>::new_dvec3(other * self.f64())
+            }
+        }
+
+        impl<A, B, C> std::ops::Div<$crate::Scalar> for $TypeName
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            type Output = $TypeName;
+
+            fn div(self, s: $crate::Scalar) -> Self {
+                use $crate::Quantity;
+                Self {
+                    v: self.v / s.f64(),
+                    phantom_1: PhantomData,
+                    phantom_2: PhantomData,
+                    phantom_3: PhantomData,
+                }
+            }
+        }
+
+        impl<A, B, C> std::ops::DivAssign<$crate::Scalar> for $TypeName
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            fn div_assign(&mut self, s: $crate::Scalar) {
+                use $crate::Quantity;
+                self.v /= s.f64();
+            }
+        }
+
+        impl<A, B, C> std::ops::Neg for $TypeName
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            type Output = $TypeName;
+
+            fn neg(mut self) -> Self::Output {
+                self.v = -self.v;
+                self
+            }
+        }
+    };
+
     ($TypeName:ty, $UnitA:path, $UnitB:path) => {
         impl<A, B> std::ops::Mul<$crate::Scalar> for $TypeName
         where
@@ -440,7 +780,7 @@ macro_rules! supports_scalar_ops {
             type Output = $TypeName;
 
             fn mul(self, other: $TypeName) -> Self::Output {
-                <$TypeName>::from(self.0.into_inner() * other.f64())
+                <$TypeName>::from(self.into_inner() * other.f64())
             }
         }
 
@@ -544,7 +884,7 @@ macro_rules! supports_scalar_ops {
             type Output = $TypeName;
 
             fn mul(self, other: $TypeName) -> Self::Output {
-                <$TypeName>::from(self.0.into_inner() * other.f64())
+                <$TypeName>::from(self.into_inner() * other.f64())
             }
         }
 
@@ -621,6 +961,24 @@ macro_rules! supports_scalar_ops {
 
 #[macro_export]
 macro_rules! supports_cancellation {
+    ($TypeNameSelf:ty, $TypeNameOther:ty, $UnitA:path, $UnitB:path, $UnitC:path) => {
+        impl<A1, B1, C1, A2, B2, C2> std::ops::Div<$TypeNameOther> for $TypeNameSelf
+        where
+            A1: $UnitA,
+            B1: $UnitB,
+            C1: $UnitC,
+            A2: $UnitA,
+            B2: $UnitB,
+            C2: $UnitC,
+        {
+            type Output = $crate::Scalar;
+
+            fn div(self, other: $TypeNameOther) -> Self::Output {
+                Self::Output::from(self.v.0 / other.v.0)
+            }
+        }
+    };
+
     ($TypeNameSelf:ty, $TypeNameOther:ty, $UnitA:path, $UnitB:path) => {
         impl<A1, B1, A2, B2> std::ops::Div<$TypeNameOther> for $TypeNameSelf
         where
@@ -654,6 +1012,93 @@ macro_rules! supports_cancellation {
 
 #[macro_export]
 macro_rules! supports_shift_ops {
+    ($TypeNameSelf:ty, $TypeNameOther:ty, $UnitA:path, $UnitB:path, $UnitC:path) => {
+        impl<A1, B1, C1, A2, B2, C2> std::ops::Add<$TypeNameOther> for $TypeNameSelf
+        where
+            A1: $UnitA,
+            B1: $UnitB,
+            C1: $UnitC,
+            A2: $UnitA,
+            B2: $UnitB,
+            C2: $UnitC,
+        {
+            type Output = $TypeNameSelf;
+
+            fn add(self, other: $TypeNameOther) -> Self {
+                Self {
+                    v: self.v + <$TypeNameSelf>::from(&other).v,
+                    phantom_1: PhantomData,
+                    phantom_2: PhantomData,
+                    phantom_3: PhantomData,
+                }
+            }
+        }
+
+        impl<A1, B1, C1, A2, B2, C2> std::ops::AddAssign<$TypeNameOther> for $TypeNameSelf
+        where
+            A1: $UnitA,
+            B1: $UnitB,
+            C1: $UnitC,
+            A2: $UnitA,
+            B2: $UnitB,
+            C2: $UnitC,
+        {
+            fn add_assign(&mut self, other: $TypeNameOther) {
+                self.v += <$TypeNameSelf>::from(&other).v;
+            }
+        }
+
+        impl<A1, B1, C1, A2, B2, C2> std::ops::Sub<$TypeNameOther> for $TypeNameSelf
+        where
+            A1: $UnitA,
+            B1: $UnitB,
+            C1: $UnitC,
+            A2: $UnitA,
+            B2: $UnitB,
+            C2: $UnitC,
+        {
+            type Output = $TypeNameSelf;
+
+            fn sub(self, other: $TypeNameOther) -> Self {
+                Self {
+                    v: self.v - <$TypeNameSelf>::from(&other).v,
+                    phantom_1: PhantomData,
+                    phantom_2: PhantomData,
+                    phantom_3: PhantomData,
+                }
+            }
+        }
+
+        impl<A1, B1, C1, A2, B2, C2> std::ops::SubAssign<$TypeNameOther> for $TypeNameSelf
+        where
+            A1: $UnitA,
+            B1: $UnitB,
+            C1: $UnitC,
+            A2: $UnitA,
+            B2: $UnitB,
+            C2: $UnitC,
+        {
+            fn sub_assign(&mut self, other: $TypeNameOther) {
+                self.v -= <$TypeNameSelf>::from(&other).v;
+            }
+        }
+
+        impl<A1, B1, C1> $crate::num_traits::identities::Zero for $TypeNameSelf
+        where
+            A1: $UnitA,
+            B1: $UnitB,
+            C1: $UnitC,
+        {
+            fn zero() -> Self {
+                <$TypeNameSelf>::from(0f64)
+            }
+
+            fn is_zero(&self) -> bool {
+                self.v == 0f64
+            }
+        }
+    };
+
     ($TypeNameSelf:ty, $TypeNameOther:ty, $UnitA:path, $UnitB:path) => {
         impl<A1, B1, A2, B2> std::ops::Add<$TypeNameOther> for $TypeNameSelf
         where
@@ -798,6 +1243,19 @@ macro_rules! supports_shift_ops {
 
 #[macro_export]
 macro_rules! supports_quantity_ops {
+    ($TypeName:ty, $UnitA:path, $UnitB:path, $UnitC:path) => {
+        impl<A, B, C> $crate::Quantity for $TypeName
+        where
+            A: $UnitA,
+            B: $UnitB,
+            C: $UnitC,
+        {
+            fn f64(&self) -> f64 {
+                self.v.0
+            }
+        }
+    };
+
     ($TypeName:ty, $UnitA:path, $UnitB:path) => {
         impl<A, B> $crate::Quantity for $TypeName
         where
@@ -0,0 +1,110 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Amperes, DynamicUnits,
+    Henries, Kilograms, Meters, Ohms, Resistance, ResistanceUnit, Scalar, Seconds, Time, Unit,
+};
+use ordered_float::OrderedFloat;
+use std::{fmt, fmt::Debug, marker::PhantomData, ops::Div};
+
+pub trait InductanceUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const HENRIES_IN_UNIT: f64;
+}
+
+// voltage * time / current: mass * length^2 / (time^2 * current^2)
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Inductance<Unit: InductanceUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_quantity_ops!(Inductance<A>, InductanceUnit);
+supports_shift_ops!(Inductance<A1>, Inductance<A2>, InductanceUnit);
+supports_scalar_ops!(Inductance<A>, InductanceUnit);
+supports_cancellation!(Inductance<A1>, Inductance<A2>, InductanceUnit);
+supports_absdiffeq!(Inductance<A>, InductanceUnit);
+supports_value_type_conversion!(Inductance<A>, InductanceUnit, impl_value_type_conversions);
+
+fn dyn_units(v: OrderedFloat<f64>) -> DynamicUnits {
+    DynamicUnits::new3o2::<Kilograms, Meters, Meters, Seconds, Seconds>(v)
+        / DynamicUnits::new2o0::<Amperes, Amperes>(1f64.into())
+}
+
+impl<U> Inductance<U>
+where
+    U: InductanceUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        dyn_units(self.v * U::HENRIES_IN_UNIT)
+    }
+}
+
+impl<Unit> fmt::Display for Inductance<Unit>
+where
+    Unit: InductanceUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a Inductance<UnitA>> for Inductance<UnitB>
+where
+    UnitA: InductanceUnit,
+    UnitB: InductanceUnit,
+{
+    fn from(v: &'a Inductance<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::HENRIES_IN_UNIT / UnitB::HENRIES_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> From<DynamicUnits> for Inductance<U>
+where
+    U: InductanceUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(dyn_units(0f64.into()));
+        Self {
+            v: f / U::HENRIES_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<A, B> Div<Resistance<B>> for Inductance<A>
+where
+    A: InductanceUnit,
+    B: ResistanceUnit,
+{
+    type Output = Time<Seconds>;
+
+    fn div(self, rhs: Resistance<B>) -> Self::Output {
+        let lhs = Inductance::<Henries>::from(&self);
+        let rhs = Resistance::<Ohms>::from(&rhs);
+        Self::Output::from(lhs.f64() / rhs.f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{amperes, henries, ohms, seconds, volts, Henries, Inductance};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_inductance() {
+        let l = henries!(2);
+        println!("{l}");
+        assert_abs_diff_eq!(l / ohms!(4), seconds!(0.5));
+    }
+
+    #[test]
+    fn test_inductance_dyn() {
+        // L = V * t / I
+        let l: Inductance<Henries> =
+            (volts!(4).as_dyn() * seconds!(1).as_dyn() / amperes!(2).as_dyn()).into();
+        assert_abs_diff_eq!(l, henries!(2));
+    }
+}
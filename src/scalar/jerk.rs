@@ -0,0 +1,112 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Acceleration,
+    DynamicUnits, LengthUnit, Time, TimeUnit,
+};
+use ordered_float::OrderedFloat;
+use std::{fmt, fmt::Debug, marker::PhantomData, ops::Mul};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Jerk<UnitLength: LengthUnit, UnitTime: TimeUnit> {
+    v: OrderedFloat<f64>,
+    phantom_1: PhantomData<UnitLength>,
+    phantom_2: PhantomData<UnitTime>,
+}
+supports_quantity_ops!(Jerk<A, B>, LengthUnit, TimeUnit);
+supports_shift_ops!(Jerk<A1, B1>, Jerk<A2, B2>, LengthUnit, TimeUnit);
+supports_scalar_ops!(Jerk<A, B>, LengthUnit, TimeUnit);
+supports_cancellation!(Jerk<A1, B1>, Jerk<A2, B2>, LengthUnit, TimeUnit);
+supports_absdiffeq!(Jerk<A, B>, LengthUnit, TimeUnit);
+supports_value_type_conversion!(Jerk<A, B>, LengthUnit, TimeUnit, impl_value_type_conversions);
+
+impl<L, T> Jerk<L, T>
+where
+    L: LengthUnit,
+    T: TimeUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new1o3::<L, T, T, T>(self.v)
+    }
+}
+
+impl<L, T> fmt::Display for Jerk<L, T>
+where
+    L: LengthUnit,
+    T: TimeUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}/{}^3", L::UNIT_SHORT_NAME, T::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, LA, TA, LB, TB> From<&'a Jerk<LA, TA>> for Jerk<LB, TB>
+where
+    LA: LengthUnit,
+    TA: TimeUnit,
+    LB: LengthUnit,
+    TB: TimeUnit,
+{
+    fn from(v: &'a Jerk<LA, TA>) -> Self {
+        let length_ratio = LA::METERS_IN_UNIT / LB::METERS_IN_UNIT;
+        let time_ratio = TB::SECONDS_IN_UNIT / TA::SECONDS_IN_UNIT;
+        Self {
+            v: v.v * length_ratio * time_ratio * time_ratio * time_ratio,
+            phantom_1: PhantomData,
+            phantom_2: PhantomData,
+        }
+    }
+}
+
+impl<L, T> From<DynamicUnits> for Jerk<L, T>
+where
+    L: LengthUnit,
+    T: TimeUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new1o3::<L, T, T, T>(0f64.into()));
+        Self {
+            v: f,
+            phantom_1: PhantomData,
+            phantom_2: PhantomData,
+        }
+    }
+}
+
+impl<LA, TA, TB> Mul<Time<TB>> for Jerk<LA, TA>
+where
+    LA: LengthUnit,
+    TA: TimeUnit,
+    TB: TimeUnit,
+{
+    type Output = Acceleration<LA, TA>;
+
+    fn mul(self, other: Time<TB>) -> Self::Output {
+        Acceleration::<LA, TA>::from(self.v.0 * Time::<TA>::from(&other).f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{feet_per_second3, meters_per_second2, meters_per_second3, scalar, seconds};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_jerk() {
+        let m_p_s3 = meters_per_second3!(100.);
+        let ft_p_s3 = feet_per_second3!(m_p_s3);
+        println!("{m_p_s3}");
+        println!("{ft_p_s3}");
+        assert_abs_diff_eq!(m_p_s3, meters_per_second3!(ft_p_s3));
+        assert_abs_diff_eq!(meters_per_second3!(2) * scalar!(2), meters_per_second3!(4));
+    }
+
+    #[test]
+    fn test_jerk_convert_acceleration() {
+        let j = meters_per_second2!(10) / seconds!(2);
+        assert_abs_diff_eq!(j, meters_per_second3!(5));
+        assert_abs_diff_eq!(j * seconds!(2), meters_per_second2!(10));
+        assert_abs_diff_eq!(meters_per_second2!(10) / j, seconds!(2));
+    }
+}
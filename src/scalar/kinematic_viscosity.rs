@@ -0,0 +1,111 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, DynamicUnits, Meters,
+    Scalar, Seconds, Unit,
+};
+use ordered_float::OrderedFloat;
+use std::{fmt, fmt::Debug, marker::PhantomData};
+
+pub trait KinematicViscosityUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const SQUARE_METERS_PER_SECOND_IN_UNIT: f64;
+}
+
+// length^2 / time
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct KinematicViscosity<Unit: KinematicViscosityUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_quantity_ops!(KinematicViscosity<A>, KinematicViscosityUnit);
+supports_shift_ops!(
+    KinematicViscosity<A1>,
+    KinematicViscosity<A2>,
+    KinematicViscosityUnit
+);
+supports_scalar_ops!(KinematicViscosity<A>, KinematicViscosityUnit);
+supports_cancellation!(
+    KinematicViscosity<A1>,
+    KinematicViscosity<A2>,
+    KinematicViscosityUnit
+);
+supports_absdiffeq!(KinematicViscosity<A>, KinematicViscosityUnit);
+supports_value_type_conversion!(
+    KinematicViscosity<A>,
+    KinematicViscosityUnit,
+    impl_value_type_conversions
+);
+
+impl<U> KinematicViscosity<U>
+where
+    U: KinematicViscosityUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new2o1::<Meters, Meters, Seconds>(
+            self.v * U::SQUARE_METERS_PER_SECOND_IN_UNIT,
+        )
+    }
+}
+
+impl<Unit> fmt::Display for KinematicViscosity<Unit>
+where
+    Unit: KinematicViscosityUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a KinematicViscosity<UnitA>> for KinematicViscosity<UnitB>
+where
+    UnitA: KinematicViscosityUnit,
+    UnitB: KinematicViscosityUnit,
+{
+    fn from(v: &'a KinematicViscosity<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::SQUARE_METERS_PER_SECOND_IN_UNIT
+                / UnitB::SQUARE_METERS_PER_SECOND_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> From<DynamicUnits> for KinematicViscosity<U>
+where
+    U: KinematicViscosityUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new2o1::<Meters, Meters, Seconds>(0f64.into()));
+        Self {
+            v: f / U::SQUARE_METERS_PER_SECOND_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        centistokes, meters2_per_second, scalar, KinematicViscosity, SquareMetersPerSecond,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_kinematic_viscosity() {
+        let nu = centistokes!(1);
+        println!("{nu}");
+        assert_abs_diff_eq!(
+            meters2_per_second!(nu),
+            meters2_per_second!(0.000_001),
+            epsilon = 1e-15
+        );
+        assert_abs_diff_eq!(centistokes!(2) * scalar!(2), centistokes!(4));
+    }
+
+    #[test]
+    fn test_kinematic_viscosity_dyn() {
+        let nu: KinematicViscosity<SquareMetersPerSecond> = centistokes!(1).as_dyn().into();
+        assert_abs_diff_eq!(nu, meters2_per_second!(0.000_001), epsilon = 1e-15);
+    }
+}
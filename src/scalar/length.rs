@@ -1,10 +1,15 @@
 use crate::{
     impl_value_type_conversions, radians, scalar, supports_absdiffeq, supports_cancellation,
     supports_quantity_ops, supports_scalar_ops, supports_shift_ops, supports_value_type_conversion,
-    Angle, Area, DynamicUnits, Radians, Scalar, Unit,
+    Angle, Area, DynamicUnits, Radians, Scalar, Time, TimeUnit, Unit, Velocity,
 };
 use ordered_float::OrderedFloat;
-use std::{fmt, fmt::Debug, marker::PhantomData, ops::Mul};
+use std::{
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
 
 pub trait LengthUnit: Unit + Copy + Debug + Eq + PartialEq + Ord + PartialOrd + 'static {
     const METERS_IN_UNIT: f64;
@@ -85,9 +90,41 @@ where
     }
 }
 
+impl<L, T> Div<Time<T>> for Length<L>
+where
+    L: LengthUnit,
+    T: TimeUnit,
+{
+    type Output = Velocity<L, T>;
+
+    fn div(self, other: Time<T>) -> Self::Output {
+        Velocity::<L, T>::from(self.v.0 / other.f64())
+    }
+}
+
+impl<LA, LB, TB> Div<Velocity<LB, TB>> for Length<LA>
+where
+    LA: LengthUnit,
+    LB: LengthUnit,
+    TB: TimeUnit,
+{
+    type Output = Time<TB>;
+
+    fn div(self, other: Velocity<LB, TB>) -> Self::Output {
+        Time::<TB>::from(self.v.0 / Velocity::<LA, TB>::from(&other).f64())
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::{feet, kilometers, meters, scalar};
+    use crate::{
+        astronomical_units, centimeters, centimeters2, centimeters3, fathoms, fathoms2, fathoms3,
+        fathoms_per_second, feet, feet2, feet3, feet_per_second, inches, inches2, inches3,
+        inches_per_second, kilometers, light_years, light_years2, light_years3,
+        light_years_per_second, liters, meters, meters2, meters3, meters_per_second, millimeters,
+        millimeters2, parsecs, parsecs2, parsecs3, parsecs_per_second, scalar, us_survey_feet,
+        us_survey_feet2, us_survey_feet3, us_survey_feet_per_second, yards, yards_per_second,
+    };
     use approx::assert_abs_diff_eq;
 
     #[test]
@@ -102,4 +139,107 @@ mod test {
     fn test_scalar_length() {
         assert_abs_diff_eq!(meters!(2) * scalar!(2), meters!(4));
     }
+
+    #[test]
+    fn test_imperial_lengths() {
+        assert_abs_diff_eq!(inches!(feet!(1)), inches!(12), epsilon = 1e-12);
+        assert_abs_diff_eq!(feet!(yards!(1)), feet!(3), epsilon = 1e-12);
+        assert_abs_diff_eq!(feet!(fathoms!(1)), feet!(6), epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            meters!(us_survey_feet!(1)),
+            meters!(0.304_800_609_6),
+            epsilon = 1e-10
+        );
+        assert_abs_diff_eq!(feet2!(inches2!(144)), feet2!(1), epsilon = 1e-12);
+        assert_abs_diff_eq!(liters!(inches3!(61.023_744)), liters!(1), epsilon = 1e-6);
+        assert_abs_diff_eq!(
+            meters_per_second!(inches_per_second!(100)),
+            meters_per_second!(2.54),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            meters_per_second!(yards_per_second!(1)),
+            meters_per_second!(0.914_4)
+        );
+    }
+
+    #[test]
+    fn test_derived_imperial_lengths() {
+        assert_abs_diff_eq!(feet2!(fathoms2!(1)), feet2!(36), epsilon = 1e-12);
+        assert_abs_diff_eq!(feet3!(fathoms3!(1)), feet3!(216), epsilon = 1e-9);
+        assert_abs_diff_eq!(
+            feet_per_second!(fathoms_per_second!(1)),
+            feet_per_second!(6),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            us_survey_feet2!(meters2!(1)),
+            us_survey_feet2!((3_937.0 / 1_200.0) * (3_937.0 / 1_200.0)),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(
+            meters3!(us_survey_feet3!(1)),
+            meters3!(0.304_800_609_6 * 0.304_800_609_6 * 0.304_800_609_6),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            meters_per_second!(us_survey_feet_per_second!(1)),
+            meters_per_second!(0.304_800_609_6),
+            epsilon = 1e-10
+        );
+    }
+
+    #[test]
+    fn test_metric_lengths() {
+        assert_abs_diff_eq!(
+            millimeters!(centimeters!(2.5)),
+            millimeters!(25),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(inches!(centimeters!(2.54)), inches!(1), epsilon = 1e-12);
+        assert_abs_diff_eq!(
+            millimeters2!(centimeters2!(1)),
+            millimeters2!(100),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(liters!(centimeters3!(1_000)), liters!(1), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_astronomical_lengths() {
+        assert_abs_diff_eq!(
+            astronomical_units!(light_years!(1)),
+            astronomical_units!(63_241.077),
+            epsilon = 0.001
+        );
+        assert_abs_diff_eq!(
+            light_years!(parsecs!(1)),
+            light_years!(3.261_564),
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(
+            astronomical_units!(parsecs!(1)),
+            astronomical_units!(206_264.806),
+            epsilon = 0.001
+        );
+        assert_abs_diff_eq!(
+            light_years2!(parsecs2!(1)).f64(),
+            3.261_564 * 3.261_564,
+            epsilon = 1e-5
+        );
+        assert_abs_diff_eq!(
+            light_years3!(parsecs3!(1)).f64(),
+            3.261_564 * 3.261_564 * 3.261_564,
+            epsilon = 1e-4
+        );
+        assert_abs_diff_eq!(
+            light_years_per_second!(parsecs_per_second!(1)).f64(),
+            3.261_564,
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(
+            meters_per_second!(light_years_per_second!(1)).f64(),
+            9_460_730_472_580_800.0
+        );
+    }
 }
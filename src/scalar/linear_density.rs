@@ -0,0 +1,141 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Area, Density,
+    DynamicUnits, Length, LengthUnit, Mass, MassUnit,
+};
+use ordered_float::OrderedFloat;
+use std::{
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
+
+// mass / length
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct LinearDensity<UnitMass: MassUnit, UnitLength: LengthUnit> {
+    v: OrderedFloat<f64>,
+    phantom_1: PhantomData<UnitMass>,
+    phantom_2: PhantomData<UnitLength>,
+}
+supports_absdiffeq!(LinearDensity<A, B>, MassUnit, LengthUnit);
+supports_quantity_ops!(LinearDensity<A, B>, MassUnit, LengthUnit);
+supports_scalar_ops!(LinearDensity<A, B>, MassUnit, LengthUnit);
+supports_cancellation!(LinearDensity<A1, B1>, LinearDensity<A2, B2>, MassUnit, LengthUnit);
+supports_shift_ops!(LinearDensity<A1, B1>, LinearDensity<A2, B2>, MassUnit, LengthUnit);
+supports_value_type_conversion!(LinearDensity<A, B>, MassUnit, LengthUnit, impl_value_type_conversions);
+
+impl<M, L> fmt::Display for LinearDensity<M, L>
+where
+    M: MassUnit,
+    L: LengthUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}/{}", M::UNIT_SHORT_NAME, L::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, MA, LA, MB, LB> From<&'a LinearDensity<MB, LB>> for LinearDensity<MA, LA>
+where
+    MA: MassUnit,
+    LA: LengthUnit,
+    MB: MassUnit,
+    LB: LengthUnit,
+{
+    fn from(v: &'a LinearDensity<MB, LB>) -> Self {
+        let mass_ratio = MB::GRAMS_IN_UNIT / MA::GRAMS_IN_UNIT;
+        let length_ratio = LA::METERS_IN_UNIT / LB::METERS_IN_UNIT;
+        Self {
+            v: v.v * mass_ratio * length_ratio,
+            phantom_1: PhantomData,
+            phantom_2: PhantomData,
+        }
+    }
+}
+
+impl<MA, LA> LinearDensity<MA, LA>
+where
+    MA: MassUnit,
+    LA: LengthUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new1o1::<MA, LA>(self.v)
+    }
+}
+
+impl<M, L> From<DynamicUnits> for LinearDensity<M, L>
+where
+    M: MassUnit,
+    L: LengthUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new1o1::<M, L>(0f64.into()));
+        Self {
+            v: f,
+            phantom_1: PhantomData,
+            phantom_2: PhantomData,
+        }
+    }
+}
+
+impl<M, L, LA> Mul<Length<LA>> for LinearDensity<M, L>
+where
+    M: MassUnit,
+    L: LengthUnit,
+    LA: LengthUnit,
+{
+    type Output = Mass<M>;
+
+    fn mul(self, rhs: Length<LA>) -> Self::Output {
+        Mass::<M>::from(self.v.0 * Length::<L>::from(&rhs).f64())
+    }
+}
+
+impl<M, L, LA> Div<Area<LA>> for LinearDensity<M, L>
+where
+    M: MassUnit,
+    L: LengthUnit,
+    LA: LengthUnit,
+{
+    type Output = Density<M, L>;
+
+    fn div(self, rhs: Area<LA>) -> Self::Output {
+        Density::<M, L>::from(self.v.0 / Area::<L>::from(&rhs).f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        feet, kilograms, kilograms_per_meter, kilograms_per_meter3, meters, meters2,
+        pounds_per_feet, Kilograms, LinearDensity, Meters,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_linear_density() {
+        let rho_l = kilograms_per_meter!(4);
+        println!("{rho_l}");
+        assert_abs_diff_eq!(
+            pounds_per_feet!(rho_l),
+            pounds_per_feet!(2.687_8),
+            epsilon = 0.000_1
+        );
+        assert_abs_diff_eq!(rho_l * meters!(3), kilograms!(12));
+        assert_abs_diff_eq!(
+            kilograms!(rho_l * feet!(1)),
+            kilograms!(1.219_2),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(rho_l / meters2!(2), kilograms_per_meter3!(2));
+    }
+
+    #[test]
+    fn test_linear_density_dyn() {
+        let rho_l: LinearDensity<Kilograms, Meters> =
+            (kilograms!(10).as_dyn() / meters!(2).as_dyn()).into();
+        assert_abs_diff_eq!(rho_l, kilograms_per_meter!(5));
+    }
+}
@@ -1,10 +1,17 @@
 use crate::{
     impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
     supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Acceleration, Area,
-    DynamicUnits, Force, ForceUnit, LengthUnit, Newtons, RotationalInertia, Scalar, TimeUnit, Unit,
+    ArealDensity, Density, DynamicUnits, Energy, Force, ForceUnit, Joules, Kilograms, Length,
+    LengthUnit, LinearDensity, Meters, Momentum, Newtons, RotationalInertia, Scalar, Seconds,
+    TimeUnit, Unit, Velocity, VelocitySquared, Volume, VolumeUnit,
 };
 use ordered_float::OrderedFloat;
-use std::{fmt, fmt::Debug, marker::PhantomData, ops::Mul};
+use std::{
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
 
 pub trait MassUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
     const GRAMS_IN_UNIT: f64;
@@ -67,7 +74,7 @@ where
             <Newtons as ForceUnit>::UnitLength,
             <Newtons as ForceUnit>::UnitTime,
         >::from(&rhs);
-        let mass = Mass::<<Newtons as ForceUnit>::UnitMass>::from(self.v.0);
+        let mass = Mass::<<Newtons as ForceUnit>::UnitMass>::from(&self);
         Self::Output::from(mass.f64() * acc.f64())
     }
 }
@@ -84,9 +91,90 @@ where
     }
 }
 
+impl<MA, LB, TB> Mul<VelocitySquared<LB, TB>> for Mass<MA>
+where
+    MA: MassUnit,
+    LB: LengthUnit,
+    TB: TimeUnit,
+{
+    type Output = Energy<Joules>;
+
+    fn mul(self, rhs: VelocitySquared<LB, TB>) -> Self::Output {
+        let mass = Mass::<Kilograms>::from(&self);
+        let v2 = VelocitySquared::<Meters, Seconds>::from(&rhs);
+        Self::Output::from(mass.f64() * v2.f64())
+    }
+}
+
+impl<MA, LB, TB> Mul<Velocity<LB, TB>> for Mass<MA>
+where
+    MA: MassUnit,
+    LB: LengthUnit,
+    TB: TimeUnit,
+{
+    type Output = Momentum<MA, LB, TB>;
+
+    fn mul(self, rhs: Velocity<LB, TB>) -> Self::Output {
+        Self::Output::from(self.f64() * rhs.f64())
+    }
+}
+
+impl<M, V> Div<Volume<V>> for Mass<M>
+where
+    M: MassUnit,
+    V: VolumeUnit,
+{
+    type Output = Density<M, V::UnitLength>;
+
+    fn div(self, rhs: Volume<V>) -> Self::Output {
+        Self::Output::from(self.v.0 / Volume::<V::UnitLength>::from(&rhs).f64())
+    }
+}
+
+impl<MA, MB, L> Div<Density<MB, L>> for Mass<MA>
+where
+    MA: MassUnit,
+    MB: MassUnit,
+    L: LengthUnit,
+{
+    type Output = Volume<L>;
+
+    fn div(self, rhs: Density<MB, L>) -> Self::Output {
+        Self::Output::from(self.v.0 / Density::<MA, L>::from(&rhs).f64())
+    }
+}
+
+impl<M, L> Div<Area<L>> for Mass<M>
+where
+    M: MassUnit,
+    L: LengthUnit,
+{
+    type Output = ArealDensity<M, L>;
+
+    fn div(self, rhs: Area<L>) -> Self::Output {
+        Self::Output::from(self.v.0 / rhs.f64())
+    }
+}
+
+impl<M, L> Div<Length<L>> for Mass<M>
+where
+    M: MassUnit,
+    L: LengthUnit,
+{
+    type Output = LinearDensity<M, L>;
+
+    fn div(self, rhs: Length<L>) -> Self::Output {
+        Self::Output::from(self.v.0 / rhs.f64())
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::{kilograms, pounds_mass, scalar};
+    use crate::{
+        earth_masses, grains, grams, grams_per_centimeter3, kilograms, kilograms_per_meter3,
+        kilograms_per_second, milligrams, ounces, pounds_mass, scalar, short_tons, solar_masses,
+        tonnes, tonnes_per_hour,
+    };
     use approx::assert_abs_diff_eq;
 
     #[test]
@@ -101,4 +189,36 @@ mod test {
     fn test_mass_scalar() {
         assert_abs_diff_eq!(pounds_mass!(2) * scalar!(2), pounds_mass!(4));
     }
+
+    #[test]
+    fn test_additional_mass_units() {
+        assert_abs_diff_eq!(kilograms!(tonnes!(2.5)), kilograms!(2_500));
+        assert_abs_diff_eq!(
+            pounds_mass!(short_tons!(1)),
+            pounds_mass!(2_000),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(ounces!(pounds_mass!(1)), ounces!(16), epsilon = 1e-12);
+        assert_abs_diff_eq!(grains!(pounds_mass!(1)), grains!(7_000), epsilon = 1e-9);
+        assert_abs_diff_eq!(grams!(milligrams!(1_500)), grams!(1.5));
+        assert_abs_diff_eq!(
+            earth_masses!(solar_masses!(1)).f64(),
+            332_950.,
+            epsilon = 10.
+        );
+    }
+
+    #[test]
+    fn test_additional_mass_densities_and_rates() {
+        assert_abs_diff_eq!(
+            kilograms_per_meter3!(grams_per_centimeter3!(1)),
+            kilograms_per_meter3!(1_000),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(
+            kilograms_per_second!(tonnes_per_hour!(3.6)),
+            kilograms_per_second!(1),
+            epsilon = 1e-12
+        );
+    }
 }
@@ -1,20 +1,48 @@
 pub(crate) mod acceleration;
+pub(crate) mod amount;
 pub(crate) mod angle;
 pub(crate) mod angular_acceleration;
+pub(crate) mod angular_damping_coefficient;
+pub(crate) mod angular_momentum;
 pub(crate) mod angular_velocity;
 pub(crate) mod area;
+pub(crate) mod areal_density;
+pub(crate) mod capacitance;
+pub(crate) mod charge;
+pub(crate) mod current;
+pub(crate) mod damping_coefficient;
+pub(crate) mod define;
 pub(crate) mod density;
 pub(crate) mod dynamic_unit;
+pub(crate) mod dynamic_viscosity;
+pub(crate) mod energy;
 pub(crate) mod force;
+pub(crate) mod frequency;
 pub(crate) mod generic;
+pub(crate) mod inductance;
+pub(crate) mod jerk;
+pub(crate) mod kinematic_viscosity;
 pub(crate) mod length;
+pub(crate) mod linear_density;
 pub(crate) mod mass;
 pub(crate) mod mass_rate;
+pub(crate) mod molar_mass;
+pub(crate) mod momentum;
+pub(crate) mod power;
 pub(crate) mod pressure;
+pub(crate) mod resistance;
 pub(crate) mod rotational_inertia;
+pub(crate) mod specific_gas_constant;
+pub(crate) mod specific_impulse;
+pub(crate) mod stiffness;
 pub(crate) mod temperature;
+pub(crate) mod temperature_delta;
 pub(crate) mod time;
 pub(crate) mod torque;
+pub(crate) mod torsional_stiffness;
+pub(crate) mod tsfc;
 pub(crate) mod velocity;
 pub(crate) mod velocity_squared;
+pub(crate) mod voltage;
 pub(crate) mod volume;
+pub(crate) mod volume_rate;
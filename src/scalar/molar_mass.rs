@@ -0,0 +1,136 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Amount, DynamicUnits,
+    JoulesPerKilogramKelvin, Kilograms, KilogramsPerMole, Mass, MassUnit, Moles, Scalar,
+    SpecificGasConstant, Unit, UNIVERSAL_GAS_CONSTANT,
+};
+use ordered_float::OrderedFloat;
+use std::{fmt, fmt::Debug, marker::PhantomData, ops::Div};
+
+pub trait MolarMassUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const KILOGRAMS_PER_MOLE_IN_UNIT: f64;
+}
+
+// mass / amount of substance
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct MolarMass<Unit: MolarMassUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_quantity_ops!(MolarMass<A>, MolarMassUnit);
+supports_shift_ops!(MolarMass<A1>, MolarMass<A2>, MolarMassUnit);
+supports_scalar_ops!(MolarMass<A>, MolarMassUnit);
+supports_cancellation!(MolarMass<A1>, MolarMass<A2>, MolarMassUnit);
+supports_absdiffeq!(MolarMass<A>, MolarMassUnit);
+supports_value_type_conversion!(MolarMass<A>, MolarMassUnit, impl_value_type_conversions);
+
+impl<U> MolarMass<U>
+where
+    U: MolarMassUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new1o1::<Kilograms, Moles>(self.v * U::KILOGRAMS_PER_MOLE_IN_UNIT)
+    }
+}
+
+impl<Unit> fmt::Display for MolarMass<Unit>
+where
+    Unit: MolarMassUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a MolarMass<UnitA>> for MolarMass<UnitB>
+where
+    UnitA: MolarMassUnit,
+    UnitB: MolarMassUnit,
+{
+    fn from(v: &'a MolarMass<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::KILOGRAMS_PER_MOLE_IN_UNIT / UnitB::KILOGRAMS_PER_MOLE_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> From<DynamicUnits> for MolarMass<U>
+where
+    U: MolarMassUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new1o1::<Kilograms, Moles>(0f64.into()));
+        Self {
+            v: f / U::KILOGRAMS_PER_MOLE_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> MolarMass<U>
+where
+    U: MolarMassUnit,
+{
+    /// The gas constant for a specific gas, R / M.
+    pub fn specific_gas_constant(&self) -> SpecificGasConstant<JoulesPerKilogramKelvin> {
+        let molar_mass = MolarMass::<KilogramsPerMole>::from(self);
+        SpecificGasConstant::from(UNIVERSAL_GAS_CONSTANT / molar_mass.f64())
+    }
+}
+
+impl<A, B> Div<MolarMass<B>> for Mass<A>
+where
+    A: MassUnit,
+    B: MolarMassUnit,
+{
+    type Output = Amount<Moles>;
+
+    fn div(self, rhs: MolarMass<B>) -> Self::Output {
+        let m = Mass::<Kilograms>::from(&self);
+        let molar_mass = MolarMass::<KilogramsPerMole>::from(&rhs);
+        Self::Output::from(m.f64() / molar_mass.f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        grams_per_mole, joules_per_kilogram_kelvin, kilograms, kilograms_per_mole, moles,
+        GramsPerMole, MolarMass,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_molar_mass() {
+        let m = grams_per_mole!(28.9647);
+        println!("{m}");
+        assert_abs_diff_eq!(
+            kilograms_per_mole!(m),
+            kilograms_per_mole!(0.028_964_7),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            kilograms!(0.032) / grams_per_mole!(16),
+            moles!(2),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_specific_gas_constant_of_air() {
+        assert_abs_diff_eq!(
+            grams_per_mole!(28.9647).specific_gas_constant(),
+            joules_per_kilogram_kelvin!(287.05),
+            epsilon = 0.01
+        );
+    }
+
+    #[test]
+    fn test_molar_mass_dyn() {
+        let m: MolarMass<GramsPerMole> = grams_per_mole!(18).as_dyn().into();
+        assert_abs_diff_eq!(m, grams_per_mole!(18), epsilon = 1e-12);
+    }
+}
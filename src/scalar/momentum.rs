@@ -0,0 +1,175 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, DynamicUnits, Force,
+    Kilograms, LengthUnit, Mass, MassUnit, Meters, Newtons, Seconds, Time, TimeUnit, Velocity,
+};
+use ordered_float::OrderedFloat;
+use std::{fmt, fmt::Debug, marker::PhantomData, ops::Div};
+
+/// Impulse (force * time) has the same dimensions as momentum.
+pub type Impulse<UnitMass, UnitLength, UnitTime> = Momentum<UnitMass, UnitLength, UnitTime>;
+
+// mass * length / time
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Momentum<UnitMass: MassUnit, UnitLength: LengthUnit, UnitTime: TimeUnit> {
+    v: OrderedFloat<f64>,
+    phantom_1: PhantomData<UnitMass>,
+    phantom_2: PhantomData<UnitLength>,
+    phantom_3: PhantomData<UnitTime>,
+}
+supports_quantity_ops!(Momentum<A, B, C>, MassUnit, LengthUnit, TimeUnit);
+supports_shift_ops!(Momentum<A1, B1, C1>, Momentum<A2, B2, C2>, MassUnit, LengthUnit, TimeUnit);
+supports_scalar_ops!(Momentum<A, B, C>, MassUnit, LengthUnit, TimeUnit);
+supports_cancellation!(Momentum<A1, B1, C1>, Momentum<A2, B2, C2>, MassUnit, LengthUnit, TimeUnit);
+supports_absdiffeq!(Momentum<A, B, C>, MassUnit, LengthUnit, TimeUnit);
+supports_value_type_conversion!(Momentum<A, B, C>, MassUnit, LengthUnit, TimeUnit, impl_value_type_conversions);
+
+impl<M, L, T> fmt::Display for Momentum<M, L, T>
+where
+    M: MassUnit,
+    L: LengthUnit,
+    T: TimeUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(
+            f,
+            "{}*{}/{}",
+            M::UNIT_SHORT_NAME,
+            L::UNIT_SHORT_NAME,
+            T::UNIT_SHORT_NAME
+        )
+    }
+}
+
+impl<'a, MA, LA, TA, MB, LB, TB> From<&'a Momentum<MA, LA, TA>> for Momentum<MB, LB, TB>
+where
+    MA: MassUnit,
+    LA: LengthUnit,
+    TA: TimeUnit,
+    MB: MassUnit,
+    LB: LengthUnit,
+    TB: TimeUnit,
+{
+    fn from(v: &'a Momentum<MA, LA, TA>) -> Self {
+        let mass_ratio = MA::GRAMS_IN_UNIT / MB::GRAMS_IN_UNIT;
+        let length_ratio = LA::METERS_IN_UNIT / LB::METERS_IN_UNIT;
+        let time_ratio = TB::SECONDS_IN_UNIT / TA::SECONDS_IN_UNIT;
+        Self {
+            v: v.v * mass_ratio * length_ratio * time_ratio,
+            phantom_1: PhantomData,
+            phantom_2: PhantomData,
+            phantom_3: PhantomData,
+        }
+    }
+}
+
+impl<M, L, T> Momentum<M, L, T>
+where
+    M: MassUnit,
+    L: LengthUnit,
+    T: TimeUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new2o1::<M, L, T>(self.v)
+    }
+}
+
+impl<M, L, T> From<DynamicUnits> for Momentum<M, L, T>
+where
+    M: MassUnit,
+    L: LengthUnit,
+    T: TimeUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new2o1::<M, L, T>(0f64.into()));
+        Self {
+            v: f,
+            phantom_1: PhantomData,
+            phantom_2: PhantomData,
+            phantom_3: PhantomData,
+        }
+    }
+}
+
+impl<MA, LA, TA, MB> Div<Mass<MB>> for Momentum<MA, LA, TA>
+where
+    MA: MassUnit,
+    LA: LengthUnit,
+    TA: TimeUnit,
+    MB: MassUnit,
+{
+    type Output = Velocity<LA, TA>;
+
+    fn div(self, other: Mass<MB>) -> Self::Output {
+        Velocity::<LA, TA>::from(self.v.0 / Mass::<MA>::from(&other).f64())
+    }
+}
+
+impl<MA, LA, TA, TB> Div<Time<TB>> for Momentum<MA, LA, TA>
+where
+    MA: MassUnit,
+    LA: LengthUnit,
+    TA: TimeUnit,
+    TB: TimeUnit,
+{
+    type Output = Force<Newtons>;
+
+    fn div(self, other: Time<TB>) -> Self::Output {
+        let momentum = Momentum::<Kilograms, Meters, Seconds>::from(&self);
+        Force::<Newtons>::from(momentum.f64() / Time::<Seconds>::from(&other).f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        kilograms, kilograms_meters_per_second, meters_per_second, newton_seconds, newtons,
+        pounds_mass, scalar, seconds, Feet, Momentum, PoundsMass, Seconds,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_momentum() {
+        let p = kilograms_meters_per_second!(100);
+        let imperial = Momentum::<PoundsMass, Feet, Seconds>::from(&p);
+        println!("{p}");
+        println!("{imperial}");
+        assert_abs_diff_eq!(
+            kilograms_meters_per_second!(imperial),
+            p,
+            epsilon = 0.000_001
+        );
+        assert_abs_diff_eq!(
+            kilograms_meters_per_second!(2) * scalar!(2),
+            kilograms_meters_per_second!(4)
+        );
+    }
+
+    #[test]
+    fn test_momentum_mass_velocity() {
+        let p = kilograms!(2) * meters_per_second!(3);
+        assert_abs_diff_eq!(p, kilograms_meters_per_second!(6));
+        assert_abs_diff_eq!(p / kilograms!(2), meters_per_second!(3));
+        assert_abs_diff_eq!(
+            meters_per_second!(p / pounds_mass!(kilograms!(2))),
+            meters_per_second!(3),
+            epsilon = 0.000_001
+        );
+    }
+
+    #[test]
+    fn test_impulse() {
+        let j = newtons!(10) * seconds!(3);
+        assert_abs_diff_eq!(j, newton_seconds!(30));
+        assert_abs_diff_eq!(j / seconds!(3), newtons!(10));
+    }
+
+    #[test]
+    fn test_conservation_of_momentum() {
+        let before = kilograms!(2) * meters_per_second!(3) + kilograms!(1) * meters_per_second!(0);
+        let after = before / (kilograms!(2) + kilograms!(1));
+        assert_abs_diff_eq!(after, meters_per_second!(2));
+    }
+}
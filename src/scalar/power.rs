@@ -0,0 +1,141 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, DynamicUnits, Energy,
+    Joules, Kilograms, Meters, Scalar, Seconds, Time, TimeUnit, Unit, Watts,
+};
+use ordered_float::OrderedFloat;
+use std::{fmt, fmt::Debug, marker::PhantomData, ops::Mul};
+
+pub trait PowerUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const WATTS_IN_UNIT: f64;
+}
+
+/// mass * length * length / time / time / time
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Power<Unit: PowerUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_absdiffeq!(Power<A>, PowerUnit);
+supports_quantity_ops!(Power<A>, PowerUnit);
+supports_scalar_ops!(Power<A>, PowerUnit);
+supports_cancellation!(Power<A1>, Power<A2>, PowerUnit);
+supports_shift_ops!(Power<A1>, Power<A2>, PowerUnit);
+supports_value_type_conversion!(Power<A>, PowerUnit, impl_value_type_conversions);
+
+impl<P> Power<P>
+where
+    P: PowerUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new3o3::<Kilograms, Meters, Meters, Seconds, Seconds, Seconds>(
+            self.v * P::WATTS_IN_UNIT,
+        )
+    }
+}
+
+impl<Unit> fmt::Display for Power<Unit>
+where
+    Unit: PowerUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a Power<UnitA>> for Power<UnitB>
+where
+    UnitA: PowerUnit,
+    UnitB: PowerUnit,
+{
+    fn from(v: &'a Power<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::WATTS_IN_UNIT / UnitB::WATTS_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<P> From<DynamicUnits> for Power<P>
+where
+    P: PowerUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new3o3::<
+            Kilograms,
+            Meters,
+            Meters,
+            Seconds,
+            Seconds,
+            Seconds,
+        >(0f64.into()));
+        Self {
+            v: f / P::WATTS_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<P, T> Mul<Time<T>> for Power<P>
+where
+    P: PowerUnit,
+    T: TimeUnit,
+{
+    type Output = Energy<Joules>;
+
+    fn mul(self, rhs: Time<T>) -> Self::Output {
+        let power = Power::<Watts>::from(&self);
+        let time = Time::<Seconds>::from(&rhs);
+        Self::Output::from(power.f64() * time.f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        foot_pounds_per_second, horsepower, joules, kilowatts, meters_per_second, newton_meters,
+        newtons, radians_per_second, scalar, seconds, watts, Horsepower, Power, Watts,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_power() {
+        let hp = horsepower!(1);
+        println!("{hp}");
+        println!("{}", watts!(hp));
+        assert_abs_diff_eq!(watts!(hp), watts!(745.699_872), epsilon = 0.000_001);
+        assert_abs_diff_eq!(kilowatts!(watts!(1_500)), kilowatts!(1.5));
+        assert_abs_diff_eq!(
+            foot_pounds_per_second!(hp),
+            foot_pounds_per_second!(550),
+            epsilon = 0.000_000_1
+        );
+    }
+
+    #[test]
+    fn test_power_scalar() {
+        assert_abs_diff_eq!(watts!(2) * scalar!(2), watts!(4));
+    }
+
+    #[test]
+    fn test_power_relations() {
+        assert_abs_diff_eq!(joules!(100) / seconds!(4), watts!(25));
+        assert_abs_diff_eq!(newtons!(10) * meters_per_second!(3), watts!(30));
+        assert_abs_diff_eq!(newton_meters!(10) * radians_per_second!(3), watts!(30));
+        assert_abs_diff_eq!(watts!(25) * seconds!(4), joules!(100));
+    }
+
+    #[test]
+    fn test_power_dyn() {
+        let p: Power<Watts> = (joules!(10).as_dyn() / seconds!(2).as_dyn()).into();
+        assert_abs_diff_eq!(p, watts!(5));
+        let p: Power<Watts> = kilowatts!(1).as_dyn().into();
+        assert_abs_diff_eq!(p, watts!(1_000));
+        let p: Power<Watts> = horsepower!(1).as_dyn().into();
+        assert_abs_diff_eq!(p, watts!(horsepower!(1)));
+        let hp: Power<Horsepower> = foot_pounds_per_second!(550).as_dyn().into();
+        assert_abs_diff_eq!(hp, horsepower!(1), epsilon = 1e-9);
+    }
+}
@@ -1,9 +1,16 @@
 use crate::{
     impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
-    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Scalar, Unit,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Area, Density,
+    DynamicUnits, Energy, Force, Joules, Kilograms, LengthUnit, MassUnit, Meters, Newtons, Pascals,
+    Scalar, Seconds, TimeUnit, Unit, Velocity, VelocitySquared, Volume, VolumeUnit,
 };
 use ordered_float::OrderedFloat;
-use std::{fmt, fmt::Debug, marker::PhantomData};
+use std::{
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
 
 pub trait PressureUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
     const PASCALS_IN_UNIT: f64;
@@ -24,6 +31,15 @@ supports_cancellation!(Pressure<A1>, Pressure<A2>, PressureUnit);
 supports_absdiffeq!(Pressure<A>, PressureUnit);
 supports_value_type_conversion!(Pressure<A>, PressureUnit, impl_value_type_conversions);
 
+impl<P> Pressure<P>
+where
+    P: PressureUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new1o3::<Kilograms, Meters, Seconds, Seconds>(self.v * P::PASCALS_IN_UNIT)
+    }
+}
+
 impl<P> fmt::Display for Pressure<P>
 where
     P: PressureUnit,
@@ -48,9 +64,90 @@ where
     }
 }
 
+impl<P> From<DynamicUnits> for Pressure<P>
+where
+    P: PressureUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new1o3::<Kilograms, Meters, Seconds, Seconds>(
+            0f64.into(),
+        ));
+        Self {
+            v: f / P::PASCALS_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<P, L> Mul<Area<L>> for Pressure<P>
+where
+    P: PressureUnit,
+    L: LengthUnit,
+{
+    type Output = Force<Newtons>;
+
+    fn mul(self, rhs: Area<L>) -> Self::Output {
+        let pressure = Pressure::<Pascals>::from(&self);
+        let area = Area::<Meters>::from(&rhs);
+        Self::Output::from(pressure.f64() * area.f64())
+    }
+}
+
+impl<P, M, L> Div<Density<M, L>> for Pressure<P>
+where
+    P: PressureUnit,
+    M: MassUnit,
+    L: LengthUnit,
+{
+    type Output = VelocitySquared<Meters, Seconds>;
+
+    fn div(self, rhs: Density<M, L>) -> Self::Output {
+        let pressure = Pressure::<Pascals>::from(&self);
+        let density = Density::<Kilograms, Meters>::from(&rhs);
+        Self::Output::from(pressure.f64() / density.f64())
+    }
+}
+
+impl<P, V> Mul<Volume<V>> for Pressure<P>
+where
+    P: PressureUnit,
+    V: VolumeUnit,
+{
+    type Output = Energy<Joules>;
+
+    fn mul(self, rhs: Volume<V>) -> Self::Output {
+        let pressure = Pressure::<Pascals>::from(&self);
+        let volume = Volume::<Meters>::from(&rhs);
+        Self::Output::from(pressure.f64() * volume.f64())
+    }
+}
+
+/// Dynamic pressure of a flow: 1/2 * density * velocity^2.
+pub fn dynamic_pressure<M, LD, LV, T>(
+    density: Density<M, LD>,
+    velocity: Velocity<LV, T>,
+) -> Pressure<Pascals>
+where
+    M: MassUnit,
+    LD: LengthUnit,
+    LV: LengthUnit,
+    T: TimeUnit,
+{
+    let density = Density::<Kilograms, Meters>::from(&density);
+    let velocity = Velocity::<Meters, Seconds>::from(&velocity);
+    Pressure::<Pascals>::from(0.5 * density.f64() * velocity.f64() * velocity.f64())
+}
+
 #[cfg(test)]
 mod test {
-    use crate::{pascals, psf};
+    use crate::{
+        atmospheres, bar, dynamic_pressure, feet2, hectopascals, inches_of_mercury,
+        kilograms_per_meter3, knots, megapascals, meters, meters2, meters_per_second,
+        meters_per_second2, millibar, millimeters_of_mercury, newtons, pascals, pounds_force, psf,
+        psi, torr, InchesOfMercury, MillimetersOfMercury, Pascals, PoundsSquareFoot,
+        PoundsSquareInch, Pressure, Torr,
+    };
     use approx::assert_abs_diff_eq;
 
     #[test]
@@ -61,4 +158,94 @@ mod test {
         println!("{pas}");
         assert_abs_diff_eq!(psf, psf!(pas), epsilon = 0.01);
     }
+
+    #[test]
+    fn test_pressure_units() {
+        assert_abs_diff_eq!(psf!(psi!(1)), psf!(144), epsilon = 1e-9);
+        assert_abs_diff_eq!(
+            pascals!(psi!(1)),
+            pascals!(6_894.757_293_168),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(pascals!(psf!(1)), pascals!(47.880_258_980), epsilon = 1e-9);
+        assert_abs_diff_eq!(
+            millibar!(atmospheres!(1)),
+            millibar!(1_013.25),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(hectopascals!(millibar!(1_013.25)), hectopascals!(1_013.25));
+        assert_abs_diff_eq!(pascals!(bar!(1)), pascals!(100_000));
+        assert_abs_diff_eq!(torr!(atmospheres!(1)), torr!(760), epsilon = 1e-9);
+        assert_abs_diff_eq!(
+            millimeters_of_mercury!(torr!(1)),
+            millimeters_of_mercury!(1),
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(
+            inches_of_mercury!(hectopascals!(1_013.25)),
+            inches_of_mercury!(29.921_3),
+            epsilon = 1e-4
+        );
+        assert_abs_diff_eq!(
+            psi!(megapascals!(20.684_271_879_505_08)),
+            psi!(3_000),
+            epsilon = 1e-9
+        );
+        assert_eq!(format!("{}", hectopascals!(1_013)), "1013hPa");
+
+        let p: Pressure<Pascals> = bar!(1).as_dyn().into();
+        assert_abs_diff_eq!(p, pascals!(100_000));
+        let p: Pressure<Pascals> = atmospheres!(1).as_dyn().into();
+        assert_abs_diff_eq!(p, pascals!(101_325));
+        let p: Pressure<Torr> = atmospheres!(1).as_dyn().into();
+        assert_abs_diff_eq!(p, torr!(760), epsilon = 1e-9);
+        let p: Pressure<MillimetersOfMercury> = pascals!(133.322_387_415).as_dyn().into();
+        assert_abs_diff_eq!(p, millimeters_of_mercury!(1), epsilon = 1e-12);
+        let p: Pressure<InchesOfMercury> = hectopascals!(1_013.25).as_dyn().into();
+        assert_abs_diff_eq!(p, inches_of_mercury!(29.921_3), epsilon = 1e-4);
+        let p: Pressure<PoundsSquareInch> = psf!(144).as_dyn().into();
+        assert_abs_diff_eq!(p, psi!(1), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_pressure_force_area() {
+        assert_abs_diff_eq!(newtons!(100) / meters2!(4), pascals!(25));
+        assert_abs_diff_eq!(pascals!(25) * meters2!(4), newtons!(100));
+        assert_abs_diff_eq!(
+            psf!(pounds_force!(100) / feet2!(4)),
+            psf!(25),
+            epsilon = 0.01
+        );
+    }
+
+    #[test]
+    fn test_pressure_density() {
+        let v2 = pascals!(100) / kilograms_per_meter3!(2);
+        assert_abs_diff_eq!(v2.sqrt(), meters_per_second!(50f64.sqrt()));
+    }
+
+    #[test]
+    fn test_dynamic_pressure() {
+        let q = dynamic_pressure(kilograms_per_meter3!(1.225), meters_per_second!(100));
+        assert_abs_diff_eq!(q, pascals!(6_125), epsilon = 1e-9);
+        let q = dynamic_pressure(kilograms_per_meter3!(1.225), knots!(100));
+        assert_abs_diff_eq!(psf!(q), psf!(33.9), epsilon = 0.1);
+    }
+
+    #[test]
+    fn test_pressure_dyn() {
+        let p: Pressure<Pascals> = (newtons!(10).as_dyn() / meters2!(2).as_dyn()).into();
+        assert_abs_diff_eq!(p, pascals!(5));
+        let p: Pressure<Pascals> = (kilograms_per_meter3!(2).as_dyn()
+            * meters_per_second2!(10).as_dyn()
+            * meters!(3).as_dyn())
+        .into();
+        assert_abs_diff_eq!(p, pascals!(60));
+        let p: Pressure<PoundsSquareFoot> = psf!(3).as_dyn().into();
+        assert_abs_diff_eq!(p, psf!(3), epsilon = 1e-12);
+        let p: Pressure<Pascals> = psi!(1).as_dyn().into();
+        assert_abs_diff_eq!(p, pascals!(psi!(1)));
+        let p: Pressure<PoundsSquareFoot> = psi!(2).as_dyn().into();
+        assert_abs_diff_eq!(p, psf!(288), epsilon = 1e-9);
+    }
 }
@@ -0,0 +1,102 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Amperes, Capacitance,
+    CapacitanceUnit, DynamicUnits, Farads, Kilograms, Meters, Ohms, Scalar, Seconds, Time, Unit,
+};
+use ordered_float::OrderedFloat;
+use std::{fmt, fmt::Debug, marker::PhantomData, ops::Mul};
+
+pub trait ResistanceUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const OHMS_IN_UNIT: f64;
+}
+
+// voltage / current: mass * length^2 / (time^3 * current^2)
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Resistance<Unit: ResistanceUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_quantity_ops!(Resistance<A>, ResistanceUnit);
+supports_shift_ops!(Resistance<A1>, Resistance<A2>, ResistanceUnit);
+supports_scalar_ops!(Resistance<A>, ResistanceUnit);
+supports_cancellation!(Resistance<A1>, Resistance<A2>, ResistanceUnit);
+supports_absdiffeq!(Resistance<A>, ResistanceUnit);
+supports_value_type_conversion!(Resistance<A>, ResistanceUnit, impl_value_type_conversions);
+
+fn dyn_units(v: OrderedFloat<f64>) -> DynamicUnits {
+    DynamicUnits::new3o3::<Kilograms, Meters, Meters, Seconds, Seconds, Seconds>(v)
+        / DynamicUnits::new2o0::<Amperes, Amperes>(1f64.into())
+}
+
+impl<U> Resistance<U>
+where
+    U: ResistanceUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        dyn_units(self.v * U::OHMS_IN_UNIT)
+    }
+}
+
+impl<Unit> fmt::Display for Resistance<Unit>
+where
+    Unit: ResistanceUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a Resistance<UnitA>> for Resistance<UnitB>
+where
+    UnitA: ResistanceUnit,
+    UnitB: ResistanceUnit,
+{
+    fn from(v: &'a Resistance<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::OHMS_IN_UNIT / UnitB::OHMS_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> From<DynamicUnits> for Resistance<U>
+where
+    U: ResistanceUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(dyn_units(0f64.into()));
+        Self {
+            v: f / U::OHMS_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<A, B> Mul<Capacitance<B>> for Resistance<A>
+where
+    A: ResistanceUnit,
+    B: CapacitanceUnit,
+{
+    type Output = Time<Seconds>;
+
+    fn mul(self, rhs: Capacitance<B>) -> Self::Output {
+        let lhs = Resistance::<Ohms>::from(&self);
+        let rhs = Capacitance::<Farads>::from(&rhs);
+        Self::Output::from(lhs.f64() * rhs.f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{farads, ohms, seconds};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_resistance() {
+        let r = ohms!(1_000);
+        println!("{r}");
+        assert_abs_diff_eq!(r * farads!(0.001), seconds!(1));
+    }
+}
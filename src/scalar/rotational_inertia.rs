@@ -1,10 +1,11 @@
 use crate::{
     impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
-    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, DynamicUnits,
-    LengthUnit, MassUnit,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, AngleUnit,
+    AngularAcceleration, AngularMomentum, AngularVelocity, DynamicUnits, Kilograms, LengthUnit,
+    MassUnit, Meters, Newtons, Radians, Seconds, TimeUnit, Torque, V3,
 };
 use ordered_float::OrderedFloat;
-use std::{fmt, fmt::Debug, marker::PhantomData};
+use std::{fmt, fmt::Debug, marker::PhantomData, ops::Mul};
 
 // mass * length^2
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
@@ -59,9 +60,76 @@ where
     }
 }
 
+impl<M, L, A, T> Mul<AngularVelocity<A, T>> for RotationalInertia<M, L>
+where
+    M: MassUnit,
+    L: LengthUnit,
+    A: AngleUnit,
+    T: TimeUnit,
+{
+    type Output = AngularMomentum<M, L, T>;
+
+    fn mul(self, rhs: AngularVelocity<A, T>) -> Self::Output {
+        let omega = AngularVelocity::<Radians, T>::from(&rhs);
+        Self::Output::from(self.v.0 * omega.f64())
+    }
+}
+
+impl<M, L, A, T> Mul<V3<AngularVelocity<A, T>>> for RotationalInertia<M, L>
+where
+    M: MassUnit,
+    L: LengthUnit,
+    A: AngleUnit,
+    T: TimeUnit,
+{
+    type Output = V3<AngularMomentum<M, L, T>>;
+
+    fn mul(self, rhs: V3<AngularVelocity<A, T>>) -> Self::Output {
+        let to_rad_per_t =
+            AngularVelocity::<Radians, T>::from(&AngularVelocity::<A, T>::from(1f64));
+        V3::new_dvec3(*rhs.dvec3() * self.v.0 * to_rad_per_t.f64())
+    }
+}
+
+impl<M, L, A, T> Mul<AngularAcceleration<A, T>> for RotationalInertia<M, L>
+where
+    M: MassUnit,
+    L: LengthUnit,
+    A: AngleUnit,
+    T: TimeUnit,
+{
+    type Output = Torque<Newtons, Meters>;
+
+    fn mul(self, rhs: AngularAcceleration<A, T>) -> Self::Output {
+        let inertia = RotationalInertia::<Kilograms, Meters>::from(&self);
+        let alpha = AngularAcceleration::<Radians, Seconds>::from(&rhs);
+        Self::Output::from(inertia.f64() * alpha.f64())
+    }
+}
+
+impl<M, L, A, T> Mul<V3<AngularAcceleration<A, T>>> for RotationalInertia<M, L>
+where
+    M: MassUnit,
+    L: LengthUnit,
+    A: AngleUnit,
+    T: TimeUnit,
+{
+    type Output = V3<Torque<Newtons, Meters>>;
+
+    fn mul(self, rhs: V3<AngularAcceleration<A, T>>) -> Self::Output {
+        let inertia = RotationalInertia::<Kilograms, Meters>::from(&self);
+        let to_rad_per_s2 =
+            AngularAcceleration::<Radians, Seconds>::from(&AngularAcceleration::<A, T>::from(1f64));
+        V3::new_dvec3(*rhs.dvec3() * inertia.f64() * to_rad_per_s2.f64())
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::{kilograms_per_meter3, slugs_per_foot3};
+    use crate::{
+        kilograms_meter2, kilograms_meter2_per_second, kilograms_per_meter3, newton_meters,
+        radians_per_second, radians_per_second2, slugs_per_foot3, V3,
+    };
     use approx::assert_abs_diff_eq;
 
     #[test]
@@ -72,4 +140,32 @@ mod test {
         println!("{kg_p_m3}");
         assert_abs_diff_eq!(s_p_f3, slugs_per_foot3!(kg_p_m3), epsilon = 0.000_000_1);
     }
+
+    #[test]
+    fn test_rotational_dynamics() {
+        let i = kilograms_meter2!(2);
+        assert_abs_diff_eq!(i * radians_per_second!(3), kilograms_meter2_per_second!(6));
+        assert_abs_diff_eq!(i * radians_per_second2!(3), newton_meters!(6));
+        assert_abs_diff_eq!(newton_meters!(6) / i, radians_per_second2!(3));
+    }
+
+    #[test]
+    fn test_rotational_dynamics_v3() {
+        let i = kilograms_meter2!(2);
+        let alpha = V3::new(
+            radians_per_second2!(1),
+            radians_per_second2!(2),
+            radians_per_second2!(3),
+        );
+        let torque = i * alpha;
+        assert_abs_diff_eq!(torque.z(), newton_meters!(6));
+        let back = torque / i;
+        assert_abs_diff_eq!(back.y(), radians_per_second2!(2));
+        let omega = V3::new(
+            radians_per_second!(1),
+            radians_per_second!(0),
+            radians_per_second!(0),
+        );
+        assert_abs_diff_eq!((i * omega).x(), kilograms_meter2_per_second!(2));
+    }
 }
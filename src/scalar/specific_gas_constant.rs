@@ -0,0 +1,232 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Amount, AmountUnit,
+    Density, DynamicUnits, JoulesPerKilogramKelvin, Kelvin, Kilograms, LengthUnit, MassUnit,
+    Meters, Moles, Pascals, Pressure, PressureUnit, Scalar, Seconds, Temperature, Unit, Volume,
+    VolumeUnit,
+};
+use ordered_float::OrderedFloat;
+use std::{fmt, fmt::Debug, marker::PhantomData};
+
+pub trait SpecificGasConstantUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const JOULES_PER_KILOGRAM_KELVIN_IN_UNIT: f64;
+}
+
+// energy / (mass * temperature): length^2 / (time^2 * temperature)
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SpecificGasConstant<Unit: SpecificGasConstantUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_quantity_ops!(SpecificGasConstant<A>, SpecificGasConstantUnit);
+supports_shift_ops!(
+    SpecificGasConstant<A1>,
+    SpecificGasConstant<A2>,
+    SpecificGasConstantUnit
+);
+supports_scalar_ops!(SpecificGasConstant<A>, SpecificGasConstantUnit);
+supports_cancellation!(
+    SpecificGasConstant<A1>,
+    SpecificGasConstant<A2>,
+    SpecificGasConstantUnit
+);
+supports_absdiffeq!(SpecificGasConstant<A>, SpecificGasConstantUnit);
+supports_value_type_conversion!(
+    SpecificGasConstant<A>,
+    SpecificGasConstantUnit,
+    impl_value_type_conversions
+);
+
+impl<U> SpecificGasConstant<U>
+where
+    U: SpecificGasConstantUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new2o3::<Meters, Meters, Seconds, Seconds, Kelvin>(
+            self.v * U::JOULES_PER_KILOGRAM_KELVIN_IN_UNIT,
+        )
+    }
+}
+
+impl<Unit> fmt::Display for SpecificGasConstant<Unit>
+where
+    Unit: SpecificGasConstantUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a SpecificGasConstant<UnitA>> for SpecificGasConstant<UnitB>
+where
+    UnitA: SpecificGasConstantUnit,
+    UnitB: SpecificGasConstantUnit,
+{
+    fn from(v: &'a SpecificGasConstant<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::JOULES_PER_KILOGRAM_KELVIN_IN_UNIT
+                / UnitB::JOULES_PER_KILOGRAM_KELVIN_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> From<DynamicUnits> for SpecificGasConstant<U>
+where
+    U: SpecificGasConstantUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new2o3::<
+            Meters,
+            Meters,
+            Seconds,
+            Seconds,
+            Kelvin,
+        >(0f64.into()));
+        Self {
+            v: f / U::JOULES_PER_KILOGRAM_KELVIN_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+/// The molar gas constant, in J/(mol*K). Exact since the 2019 SI redefinition.
+pub const UNIVERSAL_GAS_CONSTANT: f64 = 8.314_462_618_153_24;
+
+/// Density of an ideal gas: p / (R * T).
+pub fn ideal_gas_density<P, R>(
+    pressure: Pressure<P>,
+    gas_constant: SpecificGasConstant<R>,
+    temperature: Temperature<Kelvin>,
+) -> Density<Kilograms, Meters>
+where
+    P: PressureUnit,
+    R: SpecificGasConstantUnit,
+{
+    let p = Pressure::<Pascals>::from(&pressure);
+    let r = SpecificGasConstant::<JoulesPerKilogramKelvin>::from(&gas_constant);
+    Density::from(p.f64() / (r.f64() * temperature.f64()))
+}
+
+/// Pressure of an ideal gas: rho * R * T.
+pub fn ideal_gas_pressure<M, L, R>(
+    density: Density<M, L>,
+    gas_constant: SpecificGasConstant<R>,
+    temperature: Temperature<Kelvin>,
+) -> Pressure<Pascals>
+where
+    M: MassUnit,
+    L: LengthUnit,
+    R: SpecificGasConstantUnit,
+{
+    let rho = Density::<Kilograms, Meters>::from(&density);
+    let r = SpecificGasConstant::<JoulesPerKilogramKelvin>::from(&gas_constant);
+    Pressure::from(rho.f64() * r.f64() * temperature.f64())
+}
+
+/// Temperature of an ideal gas: p / (rho * R).
+pub fn ideal_gas_temperature<P, M, L, R>(
+    pressure: Pressure<P>,
+    density: Density<M, L>,
+    gas_constant: SpecificGasConstant<R>,
+) -> Temperature<Kelvin>
+where
+    P: PressureUnit,
+    M: MassUnit,
+    L: LengthUnit,
+    R: SpecificGasConstantUnit,
+{
+    let p = Pressure::<Pascals>::from(&pressure);
+    let rho = Density::<Kilograms, Meters>::from(&density);
+    let r = SpecificGasConstant::<JoulesPerKilogramKelvin>::from(&gas_constant);
+    Temperature::from(p.f64() / (rho.f64() * r.f64()))
+}
+
+/// Amount of an ideal gas held in a volume: p * V / (R * T).
+pub fn ideal_gas_amount<P, V>(
+    pressure: Pressure<P>,
+    volume: Volume<V>,
+    temperature: Temperature<Kelvin>,
+) -> Amount<Moles>
+where
+    P: PressureUnit,
+    V: VolumeUnit,
+{
+    let p = Pressure::<Pascals>::from(&pressure);
+    let v = Volume::<Meters>::from(&volume);
+    Amount::from(p.f64() * v.f64() / (UNIVERSAL_GAS_CONSTANT * temperature.f64()))
+}
+
+/// Volume occupied by an amount of ideal gas: n * R * T / p.
+pub fn ideal_gas_volume<N, P>(
+    amount: Amount<N>,
+    pressure: Pressure<P>,
+    temperature: Temperature<Kelvin>,
+) -> Volume<Meters>
+where
+    N: AmountUnit,
+    P: PressureUnit,
+{
+    let n = Amount::<Moles>::from(&amount);
+    let p = Pressure::<Pascals>::from(&pressure);
+    Volume::from(n.f64() * UNIVERSAL_GAS_CONSTANT * temperature.f64() / p.f64())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        foot_pounds_per_slug_rankine, ideal_gas_amount, ideal_gas_density, ideal_gas_pressure,
+        ideal_gas_temperature, ideal_gas_volume, joules_per_kilogram_kelvin, kelvin,
+        kilograms_per_meter3, meters3, moles, pascals, JoulesPerKilogramKelvin,
+        SpecificGasConstant,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_specific_gas_constant() {
+        let r = joules_per_kilogram_kelvin!(287.05);
+        println!("{r}");
+        assert_abs_diff_eq!(
+            foot_pounds_per_slug_rankine!(r),
+            foot_pounds_per_slug_rankine!(1_716.5),
+            epsilon = 0.1
+        );
+    }
+
+    #[test]
+    fn test_ideal_gas_sea_level() {
+        let r = joules_per_kilogram_kelvin!(287.05);
+        let rho = ideal_gas_density(pascals!(101_325), r, kelvin!(288.15));
+        assert_abs_diff_eq!(rho, kilograms_per_meter3!(1.225), epsilon = 0.001);
+        assert_abs_diff_eq!(
+            ideal_gas_pressure(rho, r, kelvin!(288.15)),
+            pascals!(101_325),
+            epsilon = 1e-6
+        );
+        assert_abs_diff_eq!(
+            ideal_gas_temperature(pascals!(101_325), rho, r).f64(),
+            288.15,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_ideal_gas_molar() {
+        let v = ideal_gas_volume(moles!(1), pascals!(101_325), kelvin!(273.15));
+        assert_abs_diff_eq!(v, meters3!(0.022_414), epsilon = 1e-6);
+        assert_abs_diff_eq!(
+            ideal_gas_amount(pascals!(101_325), v, kelvin!(273.15)),
+            moles!(1),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_specific_gas_constant_dyn() {
+        let r: SpecificGasConstant<JoulesPerKilogramKelvin> =
+            joules_per_kilogram_kelvin!(287).as_dyn().into();
+        assert_abs_diff_eq!(r, joules_per_kilogram_kelvin!(287));
+    }
+}
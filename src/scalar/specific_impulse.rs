@@ -0,0 +1,121 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, DynamicUnits,
+    KilogramsPerNewtonSecond, LengthUnit, Meters, Scalar, Seconds, TimeUnit, Tsfc, Velocity,
+    STANDARD_GRAVITY,
+};
+use ordered_float::OrderedFloat;
+use std::{fmt, fmt::Debug, marker::PhantomData};
+
+// Thrust per unit weight flow of propellant, measured in time.
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SpecificImpulse<Unit: TimeUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_quantity_ops!(SpecificImpulse<A>, TimeUnit);
+supports_shift_ops!(SpecificImpulse<A1>, SpecificImpulse<A2>, TimeUnit);
+supports_scalar_ops!(SpecificImpulse<A>, TimeUnit);
+supports_cancellation!(SpecificImpulse<A1>, SpecificImpulse<A2>, TimeUnit);
+supports_absdiffeq!(SpecificImpulse<A>, TimeUnit);
+supports_value_type_conversion!(SpecificImpulse<A>, TimeUnit, impl_value_type_conversions);
+
+impl<U> SpecificImpulse<U>
+where
+    U: TimeUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new1o0::<Seconds>(self.v * U::SECONDS_IN_UNIT)
+    }
+}
+
+impl<Unit> fmt::Display for SpecificImpulse<Unit>
+where
+    Unit: TimeUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a SpecificImpulse<UnitA>> for SpecificImpulse<UnitB>
+where
+    UnitA: TimeUnit,
+    UnitB: TimeUnit,
+{
+    fn from(v: &'a SpecificImpulse<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::SECONDS_IN_UNIT / UnitB::SECONDS_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> From<DynamicUnits> for SpecificImpulse<U>
+where
+    U: TimeUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new1o0::<Seconds>(0f64.into()));
+        Self {
+            v: f / U::SECONDS_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> SpecificImpulse<U>
+where
+    U: TimeUnit,
+{
+    pub fn from_exhaust_velocity<L, T>(velocity: Velocity<L, T>) -> Self
+    where
+        L: LengthUnit,
+        T: TimeUnit,
+    {
+        let v = Velocity::<Meters, Seconds>::from(&velocity);
+        Self::from(v.f64() / STANDARD_GRAVITY / U::SECONDS_IN_UNIT)
+    }
+
+    /// Effective exhaust velocity, Isp * g0.
+    pub fn exhaust_velocity(&self) -> Velocity<Meters, Seconds> {
+        let isp = SpecificImpulse::<Seconds>::from(self);
+        Velocity::from(isp.f64() * STANDARD_GRAVITY)
+    }
+
+    /// The thrust-specific fuel consumption equivalent to this specific impulse.
+    pub fn tsfc(&self) -> Tsfc<KilogramsPerNewtonSecond> {
+        let isp = SpecificImpulse::<Seconds>::from(self);
+        Tsfc::from(1. / (STANDARD_GRAVITY * isp.f64()))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{meters_per_second, specific_impulse, Seconds, SpecificImpulse};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_specific_impulse() {
+        let isp = specific_impulse!(300);
+        println!("{isp}");
+        assert_abs_diff_eq!(
+            isp.exhaust_velocity(),
+            meters_per_second!(2_941.995),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(
+            SpecificImpulse::<Seconds>::from_exhaust_velocity(meters_per_second!(2_941.995)),
+            isp,
+            epsilon = 1e-9
+        );
+    }
+
+    #[test]
+    fn test_specific_impulse_dyn() {
+        let isp: SpecificImpulse<Seconds> = specific_impulse!(300).as_dyn().into();
+        assert_abs_diff_eq!(isp, specific_impulse!(300));
+    }
+}
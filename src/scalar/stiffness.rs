@@ -0,0 +1,139 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, DynamicUnits, Force,
+    ForceUnit, Kilograms, Length, LengthUnit, Meters, Newtons, NewtonsPerMeter, Scalar, Seconds,
+    Unit,
+};
+use ordered_float::OrderedFloat;
+use std::{
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
+
+pub trait StiffnessUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const NEWTONS_PER_METER_IN_UNIT: f64;
+}
+
+// force / length: mass / time^2
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Stiffness<Unit: StiffnessUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_quantity_ops!(Stiffness<A>, StiffnessUnit);
+supports_shift_ops!(Stiffness<A1>, Stiffness<A2>, StiffnessUnit);
+supports_scalar_ops!(Stiffness<A>, StiffnessUnit);
+supports_cancellation!(Stiffness<A1>, Stiffness<A2>, StiffnessUnit);
+supports_absdiffeq!(Stiffness<A>, StiffnessUnit);
+supports_value_type_conversion!(Stiffness<A>, StiffnessUnit, impl_value_type_conversions);
+
+impl<U> Stiffness<U>
+where
+    U: StiffnessUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new1o2::<Kilograms, Seconds, Seconds>(self.v * U::NEWTONS_PER_METER_IN_UNIT)
+    }
+}
+
+impl<Unit> fmt::Display for Stiffness<Unit>
+where
+    Unit: StiffnessUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a Stiffness<UnitA>> for Stiffness<UnitB>
+where
+    UnitA: StiffnessUnit,
+    UnitB: StiffnessUnit,
+{
+    fn from(v: &'a Stiffness<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::NEWTONS_PER_METER_IN_UNIT / UnitB::NEWTONS_PER_METER_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> From<DynamicUnits> for Stiffness<U>
+where
+    U: StiffnessUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new1o2::<Kilograms, Seconds, Seconds>(
+            0f64.into(),
+        ));
+        Self {
+            v: f / U::NEWTONS_PER_METER_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<K, L> Mul<Length<L>> for Stiffness<K>
+where
+    K: StiffnessUnit,
+    L: LengthUnit,
+{
+    type Output = Force<Newtons>;
+
+    fn mul(self, rhs: Length<L>) -> Self::Output {
+        let lhs = Stiffness::<NewtonsPerMeter>::from(&self);
+        let rhs = Length::<Meters>::from(&rhs);
+        Self::Output::from(lhs.f64() * rhs.f64())
+    }
+}
+
+impl<F, L> Div<Length<L>> for Force<F>
+where
+    F: ForceUnit,
+    L: LengthUnit,
+{
+    type Output = Stiffness<NewtonsPerMeter>;
+
+    fn div(self, rhs: Length<L>) -> Self::Output {
+        let lhs = Force::<Newtons>::from(&self);
+        let rhs = Length::<Meters>::from(&rhs);
+        Self::Output::from(lhs.f64() / rhs.f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        feet, meters, newtons, newtons_per_meter, pounds_force, pounds_force_per_inch,
+        NewtonsPerMeter, Stiffness,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_stiffness() {
+        let k = pounds_force_per_inch!(100);
+        println!("{k}");
+        assert_abs_diff_eq!(
+            newtons_per_meter!(k),
+            newtons_per_meter!(17_512.68),
+            epsilon = 0.01
+        );
+        assert_abs_diff_eq!(newtons_per_meter!(200) * meters!(0.5), newtons!(100));
+        assert_abs_diff_eq!(newtons!(100) / meters!(0.5), newtons_per_meter!(200));
+        assert_abs_diff_eq!(
+            pounds_force!(pounds_force_per_inch!(10) * feet!(1)),
+            pounds_force!(120),
+            epsilon = 0.001
+        );
+    }
+
+    #[test]
+    fn test_stiffness_dyn() {
+        let k: Stiffness<NewtonsPerMeter> = (newtons!(10).as_dyn() / meters!(2).as_dyn()).into();
+        assert_abs_diff_eq!(k, newtons_per_meter!(5));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{kelvin, supports_value_type_conversion, Quantity, Scalar, Unit};
+use crate::{supports_value_type_conversion, Quantity, Scalar, TemperatureDelta, Unit};
 use approx::AbsDiffEq;
 use ordered_float::OrderedFloat;
 use std::{
@@ -9,6 +9,9 @@ use std::{
 };
 
 pub trait TemperatureUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    // The size of one degree, for converting differences between temperatures.
+    const KELVIN_IN_UNIT: f64;
+
     fn convert_to_kelvin(degrees_in: f64) -> f64;
     fn convert_from_kelvin(degrees_k: f64) -> f64;
 }
@@ -69,48 +72,67 @@ where
     }
 }
 
-/// Linear ops are defined on all temperature types.
-impl<UnitA, UnitB> Add<Temperature<UnitB>> for Temperature<UnitA>
+/// The difference between two absolute temperatures is a delta, not another temperature.
+impl<UnitA, UnitB> Sub<Temperature<UnitB>> for Temperature<UnitA>
+where
+    UnitA: TemperatureUnit,
+    UnitB: TemperatureUnit,
+{
+    type Output = TemperatureDelta<UnitA>;
+
+    fn sub(self, rhs: Temperature<UnitB>) -> Self::Output {
+        TemperatureDelta::<UnitA>::from((self.kelvin.0 - rhs.kelvin.0) / UnitA::KELVIN_IN_UNIT)
+    }
+}
+
+/// Shifting an absolute temperature by a delta yields another absolute temperature.
+impl<UnitA, UnitB> Add<TemperatureDelta<UnitB>> for Temperature<UnitA>
 where
     UnitA: TemperatureUnit,
     UnitB: TemperatureUnit,
 {
     type Output = Temperature<UnitA>;
 
-    fn add(self, rhs: Temperature<UnitB>) -> Self::Output {
-        Temperature::<UnitA>::from(&kelvin!(self.kelvin.0 + rhs.kelvin.0))
+    fn add(self, rhs: TemperatureDelta<UnitB>) -> Self::Output {
+        Self {
+            kelvin: self.kelvin + rhs.kelvin(),
+            phantom: PhantomData,
+        }
     }
 }
 
-impl<UnitA, UnitB> AddAssign<Temperature<UnitB>> for Temperature<UnitA>
+impl<UnitA, UnitB> AddAssign<TemperatureDelta<UnitB>> for Temperature<UnitA>
 where
     UnitA: TemperatureUnit,
     UnitB: TemperatureUnit,
 {
-    fn add_assign(&mut self, rhs: Temperature<UnitB>) {
-        self.kelvin += rhs.kelvin;
+    fn add_assign(&mut self, rhs: TemperatureDelta<UnitB>) {
+        self.kelvin += rhs.kelvin();
     }
 }
 
-impl<UnitA, UnitB> Sub<Temperature<UnitB>> for Temperature<UnitA>
+impl<UnitA, UnitB> Sub<TemperatureDelta<UnitB>> for Temperature<UnitA>
 where
     UnitA: TemperatureUnit,
     UnitB: TemperatureUnit,
 {
     type Output = Temperature<UnitA>;
 
-    fn sub(self, rhs: Temperature<UnitB>) -> Self::Output {
-        Temperature::<UnitA>::from(&kelvin!(self.kelvin.0 - rhs.kelvin.0))
+    fn sub(self, rhs: TemperatureDelta<UnitB>) -> Self::Output {
+        Self {
+            kelvin: self.kelvin - rhs.kelvin(),
+            phantom: PhantomData,
+        }
     }
 }
 
-impl<UnitA, UnitB> SubAssign<Temperature<UnitB>> for Temperature<UnitA>
+impl<UnitA, UnitB> SubAssign<TemperatureDelta<UnitB>> for Temperature<UnitA>
 where
     UnitA: TemperatureUnit,
     UnitB: TemperatureUnit,
 {
-    fn sub_assign(&mut self, rhs: Temperature<UnitB>) {
-        self.kelvin -= rhs.kelvin;
+    fn sub_assign(&mut self, rhs: TemperatureDelta<UnitB>) {
+        self.kelvin -= rhs.kelvin();
     }
 }
 
@@ -218,7 +240,8 @@ supports_value_type_conversion!(impl_temperature_unit_for_numeric_type);
 
 #[cfg(test)]
 mod test {
-    use crate::{celsius, fahrenheit, kelvin, rankine};
+    use crate::{celsius, celsius_delta, fahrenheit, fahrenheit_delta, kelvin, rankine};
+    use approx::assert_abs_diff_eq;
 
     #[test]
     fn test_meters_to_feet() {
@@ -228,4 +251,26 @@ mod test {
         println!("r: {}", rankine!(f));
         println!("k: {}", kelvin!(f));
     }
+
+    #[test]
+    fn test_temperature_delta() {
+        let dt = celsius!(25) - celsius!(20);
+        println!("dt: {dt}");
+        assert_abs_diff_eq!(dt, celsius_delta!(5), epsilon = 0.000_000_001);
+        assert_abs_diff_eq!(celsius!(20) + celsius_delta!(5), celsius!(25));
+        assert_abs_diff_eq!(
+            fahrenheit!(celsius!(20) + fahrenheit_delta!(9)),
+            fahrenheit!(77),
+            epsilon = 0.000_000_001
+        );
+        assert_abs_diff_eq!(
+            fahrenheit!(212) - celsius!(0),
+            fahrenheit_delta!(180),
+            epsilon = 0.000_000_001
+        );
+        let mut t = kelvin!(300);
+        t -= celsius_delta!(10);
+        assert_abs_diff_eq!(t, kelvin!(290));
+        assert_abs_diff_eq!(rankine!(t), rankine!(522));
+    }
 }
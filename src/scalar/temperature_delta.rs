@@ -0,0 +1,83 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Scalar,
+    TemperatureUnit,
+};
+use ordered_float::OrderedFloat;
+use std::{fmt, fmt::Debug, marker::PhantomData};
+
+/// A difference between two temperatures. Unlike an absolute Temperature, a delta has no
+/// zero point, so converting between units only scales by the size of the degree.
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct TemperatureDelta<Unit: TemperatureUnit> {
+    v: OrderedFloat<f64>, // in Unit degrees
+    phantom_1: PhantomData<Unit>,
+}
+supports_quantity_ops!(TemperatureDelta<A>, TemperatureUnit);
+supports_shift_ops!(TemperatureDelta<A1>, TemperatureDelta<A2>, TemperatureUnit);
+supports_scalar_ops!(TemperatureDelta<A>, TemperatureUnit);
+supports_cancellation!(TemperatureDelta<A1>, TemperatureDelta<A2>, TemperatureUnit);
+supports_absdiffeq!(TemperatureDelta<A>, TemperatureUnit);
+supports_value_type_conversion!(
+    TemperatureDelta<A>,
+    TemperatureUnit,
+    impl_value_type_conversions
+);
+
+impl<Unit> TemperatureDelta<Unit>
+where
+    Unit: TemperatureUnit,
+{
+    pub(crate) fn kelvin(&self) -> OrderedFloat<f64> {
+        self.v * Unit::KELVIN_IN_UNIT
+    }
+}
+
+impl<Unit> fmt::Display for TemperatureDelta<Unit>
+where
+    Unit: TemperatureUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "Δ{}", Unit::UNIT_SUFFIX)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a TemperatureDelta<UnitA>> for TemperatureDelta<UnitB>
+where
+    UnitA: TemperatureUnit,
+    UnitB: TemperatureUnit,
+{
+    fn from(v: &'a TemperatureDelta<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::KELVIN_IN_UNIT / UnitB::KELVIN_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{celsius_delta, fahrenheit_delta, kelvin_delta, rankine_delta, scalar};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_temperature_delta_conversion() {
+        let dc = celsius_delta!(10);
+        println!("{dc}");
+        println!("{}", fahrenheit_delta!(dc));
+        assert_abs_diff_eq!(fahrenheit_delta!(dc), fahrenheit_delta!(18));
+        assert_abs_diff_eq!(kelvin_delta!(dc), kelvin_delta!(10));
+        assert_abs_diff_eq!(
+            rankine_delta!(fahrenheit_delta!(1)),
+            rankine_delta!(1),
+            epsilon = 0.000_000_001
+        );
+    }
+
+    #[test]
+    fn test_temperature_delta_math() {
+        assert_abs_diff_eq!(celsius_delta!(5) + fahrenheit_delta!(9), celsius_delta!(10));
+        assert_abs_diff_eq!(celsius_delta!(5) * scalar!(2), celsius_delta!(10));
+    }
+}
@@ -1,10 +1,16 @@
 use crate::{
     impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
-    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Acceleration, Length,
-    LengthUnit, Scalar, Unit, Velocity, V3,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Acceleration,
+    DynamicUnits, Length, LengthUnit, Scalar, Seconds, Unit, Velocity, V3,
 };
 use ordered_float::OrderedFloat;
-use std::{fmt, fmt::Debug, marker::PhantomData, ops::Mul};
+use std::{
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::Mul,
+    time::{Duration, TryFromFloatSecsError},
+};
 
 pub trait TimeUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
     const SECONDS_IN_UNIT: f64;
@@ -22,6 +28,15 @@ supports_cancellation!(Time<A1>, Time<A2>, TimeUnit);
 supports_absdiffeq!(Time<A>, TimeUnit);
 supports_value_type_conversion!(Time<A>, TimeUnit, impl_value_type_conversions);
 
+impl<U> Time<U>
+where
+    U: TimeUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new1o0::<U>(self.v)
+    }
+}
+
 impl<Unit> fmt::Display for Time<Unit>
 where
     Unit: TimeUnit,
@@ -44,6 +59,39 @@ where
     }
 }
 
+impl<U> From<Duration> for Time<U>
+where
+    U: TimeUnit,
+{
+    fn from(v: Duration) -> Self {
+        Self::from(&v)
+    }
+}
+
+impl<'a, U> From<&'a Duration> for Time<U>
+where
+    U: TimeUnit,
+{
+    fn from(v: &'a Duration) -> Self {
+        Self {
+            v: OrderedFloat(v.as_secs_f64() / U::SECONDS_IN_UNIT),
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+// Fails if the time is negative, not finite, or too large for a Duration.
+impl<U> TryFrom<Time<U>> for Duration
+where
+    U: TimeUnit,
+{
+    type Error = TryFromFloatSecsError;
+
+    fn try_from(v: Time<U>) -> Result<Self, Self::Error> {
+        Duration::try_from_secs_f64(Time::<Seconds>::from(&v).f64())
+    }
+}
+
 impl<UnitT0, UnitL, UnitT1> Mul<V3<Velocity<UnitL, UnitT1>>> for Time<UnitT0>
 where
     UnitT0: TimeUnit,
@@ -98,8 +146,12 @@ where
 
 #[cfg(test)]
 mod test {
-    use crate::{hours, scalar, seconds};
+    use crate::{
+        days, hours, julian_years, microseconds, milliseconds, minutes, nanoseconds, scalar,
+        seconds, weeks, Seconds, Time,
+    };
     use approx::assert_abs_diff_eq;
+    use std::time::Duration;
 
     #[test]
     fn test_time() {
@@ -113,4 +165,40 @@ mod test {
     fn test_time_scalar() {
         assert_abs_diff_eq!(seconds!(2) * scalar!(2), seconds!(4));
     }
+
+    #[test]
+    fn test_time_units() {
+        assert_abs_diff_eq!(milliseconds!(seconds!(1.5)), milliseconds!(1_500));
+        assert_abs_diff_eq!(
+            microseconds!(milliseconds!(2)),
+            microseconds!(2_000),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(
+            nanoseconds!(microseconds!(3)),
+            nanoseconds!(3_000),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(minutes!(hours!(2)), minutes!(120));
+        assert_abs_diff_eq!(hours!(days!(1)), hours!(24));
+        assert_abs_diff_eq!(days!(weeks!(2)), days!(14));
+        assert_abs_diff_eq!(days!(julian_years!(1)), days!(365.25));
+    }
+
+    #[test]
+    fn test_duration_interop() {
+        let t: Time<Seconds> = Duration::from_millis(1_500).into();
+        assert_abs_diff_eq!(t, seconds!(1.5));
+        assert_abs_diff_eq!(
+            milliseconds!(Duration::from_micros(2_500)),
+            milliseconds!(2.5)
+        );
+
+        let d = Duration::try_from(minutes!(2)).unwrap();
+        assert_eq!(d, Duration::from_secs(120));
+        let d = Duration::try_from(nanoseconds!(1_500)).unwrap();
+        assert_eq!(d, Duration::from_nanos(1_500));
+        assert!(Duration::try_from(seconds!(-1)).is_err());
+        assert!(Duration::try_from(seconds!(f64::NAN)).is_err());
+    }
 }
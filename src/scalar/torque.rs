@@ -1,10 +1,16 @@
 use crate::{
     impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
-    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, DynamicUnits,
-    ForceUnit, LengthUnit,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, AngleUnit,
+    AngularAcceleration, AngularVelocity, DynamicUnits, ForceUnit, Kilograms, LengthUnit, MassUnit,
+    Meters, Newtons, Power, Radians, RotationalInertia, Seconds, TimeUnit, Watts, V3,
 };
 use ordered_float::OrderedFloat;
-use std::{fmt, fmt::Debug, marker::PhantomData};
+use std::{
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
 
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
 pub struct Torque<UnitForce: ForceUnit, UnitLength: LengthUnit> {
@@ -54,7 +60,7 @@ where
     L: LengthUnit,
 {
     pub fn as_dyn(&self) -> DynamicUnits {
-        DynamicUnits::new3o2::<F::UnitMass, F::UnitLength, L, F::UnitTime, F::UnitTime>(self.v)
+        DynamicUnits::new3o2::<Kilograms, Meters, L, Seconds, Seconds>(self.v * F::NEWTONS_IN_UNIT)
     }
 }
 
@@ -65,24 +71,71 @@ where
 {
     fn from(v: DynamicUnits) -> Self {
         let f = v.ordered_float();
-        v.assert_units_equal(DynamicUnits::new3o2::<
-            F::UnitMass,
-            F::UnitLength,
-            L,
-            F::UnitTime,
-            F::UnitTime,
-        >(0f64.into()));
+        v.assert_units_equal(
+            DynamicUnits::new3o2::<Kilograms, Meters, L, Seconds, Seconds>(0f64.into()),
+        );
         Self {
-            v: f,
+            v: f / F::NEWTONS_IN_UNIT,
             phantom_1: PhantomData,
             phantom_2: PhantomData,
         }
     }
 }
 
+impl<F, L, A, T> Mul<AngularVelocity<A, T>> for Torque<F, L>
+where
+    F: ForceUnit,
+    L: LengthUnit,
+    A: AngleUnit,
+    T: TimeUnit,
+{
+    type Output = Power<Watts>;
+
+    fn mul(self, rhs: AngularVelocity<A, T>) -> Self::Output {
+        let torque = Torque::<Newtons, Meters>::from(&self);
+        let omega = AngularVelocity::<Radians, Seconds>::from(&rhs);
+        Self::Output::from(torque.f64() * omega.f64())
+    }
+}
+
+impl<F, L, M, LI> Div<RotationalInertia<M, LI>> for Torque<F, L>
+where
+    F: ForceUnit,
+    L: LengthUnit,
+    M: MassUnit,
+    LI: LengthUnit,
+{
+    type Output = AngularAcceleration<Radians, Seconds>;
+
+    fn div(self, rhs: RotationalInertia<M, LI>) -> Self::Output {
+        let torque = Torque::<Newtons, Meters>::from(&self);
+        let inertia = RotationalInertia::<Kilograms, Meters>::from(&rhs);
+        Self::Output::from(torque.f64() / inertia.f64())
+    }
+}
+
+impl<F, L, M, LI> Div<RotationalInertia<M, LI>> for V3<Torque<F, L>>
+where
+    F: ForceUnit,
+    L: LengthUnit,
+    M: MassUnit,
+    LI: LengthUnit,
+{
+    type Output = V3<AngularAcceleration<Radians, Seconds>>;
+
+    fn div(self, rhs: RotationalInertia<M, LI>) -> Self::Output {
+        let to_newton_meters = Torque::<Newtons, Meters>::from(&Torque::<F, L>::from(1f64));
+        let inertia = RotationalInertia::<Kilograms, Meters>::from(&rhs);
+        V3::new_dvec3(*self.dvec3() * to_newton_meters.f64() / inertia.f64())
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::newton_meters;
+    use crate::{
+        feet, kilonewtons, meters, newton_meters, pounds_force, Feet, Meters, Newtons, PoundsForce,
+        Torque,
+    };
     use approx::assert_abs_diff_eq;
 
     #[test]
@@ -91,4 +144,18 @@ mod test {
         println!("{nm}");
         assert_abs_diff_eq!(nm, newton_meters!(100.));
     }
+
+    #[test]
+    fn test_torque_dyn() {
+        let lbf_ft: Torque<PoundsForce, Feet> =
+            (pounds_force!(1).as_dyn() * feet!(1).as_dyn()).into();
+        assert_abs_diff_eq!(lbf_ft.f64(), 1., epsilon = 1e-12);
+        let lbf_ft: Torque<PoundsForce, Feet> = (pounds_force!(2) * feet!(3)).as_dyn().into();
+        assert_abs_diff_eq!(lbf_ft.f64(), 6., epsilon = 1e-12);
+
+        let nm: Torque<Newtons, Meters> = (kilonewtons!(1) * meters!(1)).as_dyn().into();
+        assert_abs_diff_eq!(nm, newton_meters!(1_000), epsilon = 1e-9);
+        let nm: Torque<Newtons, Meters> = (kilonewtons!(1).as_dyn() * meters!(1).as_dyn()).into();
+        assert_abs_diff_eq!(nm, newton_meters!(1_000), epsilon = 1e-9);
+    }
 }
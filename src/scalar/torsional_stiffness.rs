@@ -0,0 +1,152 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Angle, AngleUnit,
+    DynamicUnits, ForceUnit, Kilograms, LengthUnit, Meters, NewtonMetersPerRadian, Newtons,
+    Radians, Scalar, Seconds, Torque, Unit,
+};
+use ordered_float::OrderedFloat;
+use std::{
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
+
+pub trait TorsionalStiffnessUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const NEWTON_METERS_PER_RADIAN_IN_UNIT: f64;
+}
+
+// torque / angle: mass * length^2 / time^2 (radians cancel)
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct TorsionalStiffness<Unit: TorsionalStiffnessUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_quantity_ops!(TorsionalStiffness<A>, TorsionalStiffnessUnit);
+supports_shift_ops!(
+    TorsionalStiffness<A1>,
+    TorsionalStiffness<A2>,
+    TorsionalStiffnessUnit
+);
+supports_scalar_ops!(TorsionalStiffness<A>, TorsionalStiffnessUnit);
+supports_cancellation!(
+    TorsionalStiffness<A1>,
+    TorsionalStiffness<A2>,
+    TorsionalStiffnessUnit
+);
+supports_absdiffeq!(TorsionalStiffness<A>, TorsionalStiffnessUnit);
+supports_value_type_conversion!(
+    TorsionalStiffness<A>,
+    TorsionalStiffnessUnit,
+    impl_value_type_conversions
+);
+
+impl<U> TorsionalStiffness<U>
+where
+    U: TorsionalStiffnessUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new3o2::<Kilograms, Meters, Meters, Seconds, Seconds>(
+            self.v * U::NEWTON_METERS_PER_RADIAN_IN_UNIT,
+        )
+    }
+}
+
+impl<Unit> fmt::Display for TorsionalStiffness<Unit>
+where
+    Unit: TorsionalStiffnessUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a TorsionalStiffness<UnitA>> for TorsionalStiffness<UnitB>
+where
+    UnitA: TorsionalStiffnessUnit,
+    UnitB: TorsionalStiffnessUnit,
+{
+    fn from(v: &'a TorsionalStiffness<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::NEWTON_METERS_PER_RADIAN_IN_UNIT
+                / UnitB::NEWTON_METERS_PER_RADIAN_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> From<DynamicUnits> for TorsionalStiffness<U>
+where
+    U: TorsionalStiffnessUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new3o2::<
+            Kilograms,
+            Meters,
+            Meters,
+            Seconds,
+            Seconds,
+        >(0f64.into()));
+        Self {
+            v: f / U::NEWTON_METERS_PER_RADIAN_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<K, A> Mul<Angle<A>> for TorsionalStiffness<K>
+where
+    K: TorsionalStiffnessUnit,
+    A: AngleUnit,
+{
+    type Output = Torque<Newtons, Meters>;
+
+    fn mul(self, rhs: Angle<A>) -> Self::Output {
+        let lhs = TorsionalStiffness::<NewtonMetersPerRadian>::from(&self);
+        let rhs = Angle::<Radians>::from(&rhs);
+        Self::Output::from(lhs.f64() * rhs.f64())
+    }
+}
+
+impl<F, L, A> Div<Angle<A>> for Torque<F, L>
+where
+    F: ForceUnit,
+    L: LengthUnit,
+    A: AngleUnit,
+{
+    type Output = TorsionalStiffness<NewtonMetersPerRadian>;
+
+    fn div(self, rhs: Angle<A>) -> Self::Output {
+        let lhs = Torque::<Newtons, Meters>::from(&self);
+        let rhs = Angle::<Radians>::from(&rhs);
+        Self::Output::from(lhs.f64() / rhs.f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        degrees, newton_meters, newton_meters_per_radian, radians, NewtonMetersPerRadian,
+        TorsionalStiffness,
+    };
+    use approx::assert_abs_diff_eq;
+    use std::f64::consts::PI;
+
+    #[test]
+    fn test_torsional_stiffness() {
+        let k = newton_meters_per_radian!(10);
+        println!("{k}");
+        assert_abs_diff_eq!(k * radians!(2), newton_meters!(20));
+        assert_abs_diff_eq!(k * degrees!(180), newton_meters!(10. * PI), epsilon = 1e-12);
+        assert_abs_diff_eq!(newton_meters!(20) / radians!(2), k);
+    }
+
+    #[test]
+    fn test_torsional_stiffness_dyn() {
+        let k: TorsionalStiffness<NewtonMetersPerRadian> =
+            (newton_meters!(20).as_dyn() / radians!(2).as_dyn()).into();
+        assert_abs_diff_eq!(k, newton_meters_per_radian!(10));
+    }
+}
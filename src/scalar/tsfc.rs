@@ -0,0 +1,179 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, DynamicUnits, Force,
+    ForceUnit, Kilograms, KilogramsPerNewtonSecond, MassRate, MassUnit, Meters, Newtons, Scalar,
+    Seconds, SpecificImpulse, TimeUnit, Unit, STANDARD_GRAVITY,
+};
+use ordered_float::OrderedFloat;
+use std::{
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
+
+pub trait TsfcUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const KILOGRAMS_PER_NEWTON_SECOND_IN_UNIT: f64;
+}
+
+// Thrust-specific fuel consumption
+// mass rate / force: time / length
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Tsfc<Unit: TsfcUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_quantity_ops!(Tsfc<A>, TsfcUnit);
+supports_shift_ops!(Tsfc<A1>, Tsfc<A2>, TsfcUnit);
+supports_scalar_ops!(Tsfc<A>, TsfcUnit);
+supports_cancellation!(Tsfc<A1>, Tsfc<A2>, TsfcUnit);
+supports_absdiffeq!(Tsfc<A>, TsfcUnit);
+supports_value_type_conversion!(Tsfc<A>, TsfcUnit, impl_value_type_conversions);
+
+impl<U> Tsfc<U>
+where
+    U: TsfcUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new1o1::<Seconds, Meters>(self.v * U::KILOGRAMS_PER_NEWTON_SECOND_IN_UNIT)
+    }
+}
+
+impl<Unit> fmt::Display for Tsfc<Unit>
+where
+    Unit: TsfcUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a Tsfc<UnitA>> for Tsfc<UnitB>
+where
+    UnitA: TsfcUnit,
+    UnitB: TsfcUnit,
+{
+    fn from(v: &'a Tsfc<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::KILOGRAMS_PER_NEWTON_SECOND_IN_UNIT
+                / UnitB::KILOGRAMS_PER_NEWTON_SECOND_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> From<DynamicUnits> for Tsfc<U>
+where
+    U: TsfcUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new1o1::<Seconds, Meters>(0f64.into()));
+        Self {
+            v: f / U::KILOGRAMS_PER_NEWTON_SECOND_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> Tsfc<U>
+where
+    U: TsfcUnit,
+{
+    /// The specific impulse equivalent to this fuel consumption.
+    pub fn specific_impulse(&self) -> SpecificImpulse<Seconds> {
+        let tsfc = Tsfc::<KilogramsPerNewtonSecond>::from(self);
+        SpecificImpulse::from(1. / (STANDARD_GRAVITY * tsfc.f64()))
+    }
+}
+
+impl<M, T, F> Div<Force<F>> for MassRate<M, T>
+where
+    M: MassUnit,
+    T: TimeUnit,
+    F: ForceUnit,
+{
+    type Output = Tsfc<KilogramsPerNewtonSecond>;
+
+    fn div(self, rhs: Force<F>) -> Self::Output {
+        let mass_rate = MassRate::<Kilograms, Seconds>::from(&self);
+        let force = Force::<Newtons>::from(&rhs);
+        Self::Output::from(mass_rate.f64() / force.f64())
+    }
+}
+
+impl<U, F> Mul<Force<F>> for Tsfc<U>
+where
+    U: TsfcUnit,
+    F: ForceUnit,
+{
+    type Output = MassRate<Kilograms, Seconds>;
+
+    fn mul(self, rhs: Force<F>) -> Self::Output {
+        let tsfc = Tsfc::<KilogramsPerNewtonSecond>::from(&self);
+        let force = Force::<Newtons>::from(&rhs);
+        Self::Output::from(tsfc.f64() * force.f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        grams_per_kilonewton_second, kilograms_per_second, newtons, pounds_per_pound_force_hour,
+        specific_impulse, KilogramsPerNewtonSecond, Tsfc,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_tsfc() {
+        let tsfc = pounds_per_pound_force_hour!(0.5);
+        println!("{tsfc}");
+        assert_abs_diff_eq!(
+            grams_per_kilonewton_second!(tsfc),
+            grams_per_kilonewton_second!(14.16),
+            epsilon = 0.01
+        );
+    }
+
+    #[test]
+    fn test_tsfc_from_mass_rate() {
+        let tsfc = kilograms_per_second!(2) / newtons!(100_000);
+        assert_abs_diff_eq!(
+            grams_per_kilonewton_second!(tsfc),
+            grams_per_kilonewton_second!(20),
+            epsilon = 1e-12
+        );
+        assert_abs_diff_eq!(
+            tsfc * newtons!(50_000),
+            kilograms_per_second!(1),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_tsfc_specific_impulse() {
+        // 1 lb/(lbf*h) is exactly 3600s of Isp.
+        assert_abs_diff_eq!(
+            pounds_per_pound_force_hour!(1).specific_impulse(),
+            specific_impulse!(3_600),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(
+            pounds_per_pound_force_hour!(specific_impulse!(7_200).tsfc()),
+            pounds_per_pound_force_hour!(0.5),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_tsfc_dyn() {
+        let tsfc: Tsfc<KilogramsPerNewtonSecond> =
+            (kilograms_per_second!(2).as_dyn() / newtons!(100_000).as_dyn()).into();
+        assert_abs_diff_eq!(
+            grams_per_kilonewton_second!(tsfc),
+            grams_per_kilonewton_second!(20),
+            epsilon = 1e-12
+        );
+    }
+}
@@ -126,9 +126,26 @@ where
     }
 }
 
+impl<LA, TA, LB, TB> Div<Acceleration<LB, TB>> for Velocity<LA, TA>
+where
+    LA: LengthUnit,
+    TA: TimeUnit,
+    LB: LengthUnit,
+    TB: TimeUnit,
+{
+    type Output = Time<TA>;
+
+    fn div(self, other: Acceleration<LB, TB>) -> Self::Output {
+        Time::<TA>::from(self.v.0 / Acceleration::<LA, TA>::from(&other).f64())
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::{meters_per_second, miles_per_hour, scalar};
+    use crate::{
+        feet_per_second2, meters, meters_per_second, meters_per_second2, miles_per_hour, scalar,
+        seconds,
+    };
     use approx::assert_abs_diff_eq;
 
     #[test]
@@ -153,4 +170,17 @@ mod test {
             meters_per_second!(6f64) / meters_per_second!(3f64)
         )
     }
+
+    #[test]
+    fn test_velocity_kinematic_chain() {
+        let v = meters!(100) / seconds!(10);
+        assert_abs_diff_eq!(v, meters_per_second!(10));
+        assert_abs_diff_eq!(meters!(100) / v, seconds!(10));
+        assert_abs_diff_eq!(v / meters_per_second2!(2), seconds!(5));
+        assert_abs_diff_eq!(
+            v / feet_per_second2!(meters_per_second2!(2)),
+            seconds!(5),
+            epsilon = 0.000_000_1
+        );
+    }
 }
@@ -0,0 +1,144 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Amperes, Current,
+    CurrentUnit, DynamicUnits, Kilograms, Meters, Ohms, Power, Resistance, ResistanceUnit, Scalar,
+    Seconds, Unit, Volts, Watts,
+};
+use ordered_float::OrderedFloat;
+use std::{
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
+
+pub trait VoltageUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const VOLTS_IN_UNIT: f64;
+}
+
+// power / current: mass * length^2 / (time^3 * current)
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Voltage<Unit: VoltageUnit> {
+    v: OrderedFloat<f64>, // in Unit
+    phantom_1: PhantomData<Unit>,
+}
+supports_quantity_ops!(Voltage<A>, VoltageUnit);
+supports_shift_ops!(Voltage<A1>, Voltage<A2>, VoltageUnit);
+supports_scalar_ops!(Voltage<A>, VoltageUnit);
+supports_cancellation!(Voltage<A1>, Voltage<A2>, VoltageUnit);
+supports_absdiffeq!(Voltage<A>, VoltageUnit);
+supports_value_type_conversion!(Voltage<A>, VoltageUnit, impl_value_type_conversions);
+
+fn dyn_units(v: OrderedFloat<f64>) -> DynamicUnits {
+    DynamicUnits::new3o3::<Kilograms, Meters, Meters, Seconds, Seconds, Seconds>(v)
+        / DynamicUnits::new1o0::<Amperes>(1f64.into())
+}
+
+impl<U> Voltage<U>
+where
+    U: VoltageUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        dyn_units(self.v * U::VOLTS_IN_UNIT)
+    }
+}
+
+impl<Unit> fmt::Display for Voltage<Unit>
+where
+    Unit: VoltageUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(f, "{}", Unit::UNIT_SHORT_NAME)
+    }
+}
+
+impl<'a, UnitA, UnitB> From<&'a Voltage<UnitA>> for Voltage<UnitB>
+where
+    UnitA: VoltageUnit,
+    UnitB: VoltageUnit,
+{
+    fn from(v: &'a Voltage<UnitA>) -> Self {
+        Self {
+            v: v.v * UnitA::VOLTS_IN_UNIT / UnitB::VOLTS_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<U> From<DynamicUnits> for Voltage<U>
+where
+    U: VoltageUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(dyn_units(0f64.into()));
+        Self {
+            v: f / U::VOLTS_IN_UNIT,
+            phantom_1: PhantomData,
+        }
+    }
+}
+
+impl<A, B> Div<Current<B>> for Voltage<A>
+where
+    A: VoltageUnit,
+    B: CurrentUnit,
+{
+    type Output = Resistance<Ohms>;
+
+    fn div(self, rhs: Current<B>) -> Self::Output {
+        let lhs = Voltage::<Volts>::from(&self);
+        let rhs = Current::<Amperes>::from(&rhs);
+        Self::Output::from(lhs.f64() / rhs.f64())
+    }
+}
+
+impl<A, B> Div<Resistance<B>> for Voltage<A>
+where
+    A: VoltageUnit,
+    B: ResistanceUnit,
+{
+    type Output = Current<Amperes>;
+
+    fn div(self, rhs: Resistance<B>) -> Self::Output {
+        let lhs = Voltage::<Volts>::from(&self);
+        let rhs = Resistance::<Ohms>::from(&rhs);
+        Self::Output::from(lhs.f64() / rhs.f64())
+    }
+}
+
+impl<A, B> Mul<Current<B>> for Voltage<A>
+where
+    A: VoltageUnit,
+    B: CurrentUnit,
+{
+    type Output = Power<Watts>;
+
+    fn mul(self, rhs: Current<B>) -> Self::Output {
+        let lhs = Voltage::<Volts>::from(&self);
+        let rhs = Current::<Amperes>::from(&rhs);
+        Self::Output::from(lhs.f64() * rhs.f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{amperes, ohms, volts, watts, Ohms, Resistance};
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_voltage() {
+        let v = volts!(12);
+        println!("{v}");
+        assert_abs_diff_eq!(v / amperes!(3), ohms!(4));
+        assert_abs_diff_eq!(v / ohms!(4), amperes!(3));
+        assert_abs_diff_eq!(v * amperes!(3), watts!(36));
+    }
+
+    #[test]
+    fn test_voltage_dyn() {
+        let r: Resistance<Ohms> = (volts!(12).as_dyn() / amperes!(3).as_dyn()).into();
+        assert_abs_diff_eq!(r, ohms!(4));
+    }
+}
@@ -1,51 +1,84 @@
 use crate::{
     impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
-    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Area, DynamicUnits,
-    Length, LengthUnit, Scalar,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Area, Density,
+    DynamicUnits, Length, LengthUnit, Mass, MassUnit, Scalar, Time, TimeUnit, Unit, VolumeRate,
 };
 use ordered_float::OrderedFloat;
-use std::{fmt, fmt::Debug, marker::PhantomData, ops::Div};
+use std::{
+    fmt,
+    fmt::Debug,
+    marker::PhantomData,
+    ops::{Div, Mul},
+};
+
+/// Every length unit is also a volume unit (its cube), but some volume units, like liters and
+/// gallons, do not derive from any length.
+pub trait VolumeUnit: Unit + Copy + Debug + Eq + PartialEq + 'static {
+    const CUBIC_METERS_IN_UNIT: f64;
+
+    // Appended to UNIT_SHORT_NAME when displaying a volume.
+    const VOLUME_SUFFIX: &'static str;
+
+    type UnitLength: LengthUnit;
+
+    // The size of this unit in UnitLength^3, which is how volumes appear in dynamic units.
+    const LENGTH_CUBED_IN_UNIT: f64 = Self::CUBIC_METERS_IN_UNIT
+        / (<Self::UnitLength as LengthUnit>::METERS_IN_UNIT
+            * <Self::UnitLength as LengthUnit>::METERS_IN_UNIT
+            * <Self::UnitLength as LengthUnit>::METERS_IN_UNIT);
+}
+
+impl<L> VolumeUnit for L
+where
+    L: LengthUnit,
+{
+    const CUBIC_METERS_IN_UNIT: f64 = L::METERS_IN_UNIT * L::METERS_IN_UNIT * L::METERS_IN_UNIT;
+    const VOLUME_SUFFIX: &'static str = "^3";
+
+    type UnitLength = L;
+}
 
 #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
-pub struct Volume<Unit: LengthUnit> {
-    v: OrderedFloat<f64>, // in Unit^2
+pub struct Volume<Unit: VolumeUnit> {
+    v: OrderedFloat<f64>, // in Unit (Unit^3 for lengths)
     phantom_1: PhantomData<Unit>,
 }
-supports_quantity_ops!(Volume<A>, LengthUnit);
-supports_shift_ops!(Volume<A1>, Volume<A2>, LengthUnit);
-supports_scalar_ops!(Volume<A>, LengthUnit);
-supports_cancellation!(Volume<A1>, Volume<A2>, LengthUnit);
-supports_absdiffeq!(Volume<A>, LengthUnit);
-supports_value_type_conversion!(Volume<A>, LengthUnit, impl_value_type_conversions);
+supports_quantity_ops!(Volume<A>, VolumeUnit);
+supports_shift_ops!(Volume<A1>, Volume<A2>, VolumeUnit);
+supports_scalar_ops!(Volume<A>, VolumeUnit);
+supports_cancellation!(Volume<A1>, Volume<A2>, VolumeUnit);
+supports_absdiffeq!(Volume<A>, VolumeUnit);
+supports_value_type_conversion!(Volume<A>, VolumeUnit, impl_value_type_conversions);
 
 impl<Unit> Volume<Unit>
 where
-    Unit: LengthUnit,
+    Unit: VolumeUnit,
 {
     pub fn as_dyn(&self) -> DynamicUnits {
-        DynamicUnits::new3o0::<Unit, Unit, Unit>(self.v)
+        DynamicUnits::new3o0::<Unit::UnitLength, Unit::UnitLength, Unit::UnitLength>(
+            self.v * Unit::LENGTH_CUBED_IN_UNIT,
+        )
     }
 }
 
 impl<Unit> fmt::Display for Volume<Unit>
 where
-    Unit: LengthUnit,
+    Unit: VolumeUnit,
 {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         fmt::Display::fmt(&self.v.0, f)?;
-        write!(f, "{}^3", Unit::UNIT_SHORT_NAME)
+        write!(f, "{}{}", Unit::UNIT_SHORT_NAME, Unit::VOLUME_SUFFIX)
     }
 }
 
 impl<'a, UnitA, UnitB> From<&'a Volume<UnitA>> for Volume<UnitB>
 where
-    UnitA: LengthUnit,
-    UnitB: LengthUnit,
+    UnitA: VolumeUnit,
+    UnitB: VolumeUnit,
 {
     fn from(v: &'a Volume<UnitA>) -> Self {
-        let ratio = UnitA::METERS_IN_UNIT / UnitB::METERS_IN_UNIT;
         Self {
-            v: v.v * ratio * ratio,
+            v: v.v * UnitA::CUBIC_METERS_IN_UNIT / UnitB::CUBIC_METERS_IN_UNIT,
             phantom_1: PhantomData,
         }
     }
@@ -54,20 +87,61 @@ where
 impl<UnitA, UnitB> Div<Length<UnitA>> for Volume<UnitB>
 where
     UnitA: LengthUnit,
-    UnitB: LengthUnit,
+    UnitB: VolumeUnit,
 {
-    type Output = Area<UnitB>;
+    type Output = Area<UnitB::UnitLength>;
 
     fn div(self, other: Length<UnitA>) -> Self::Output {
-        Area::<UnitB>::from(self.v.0 / Length::<UnitB>::from(&other).f64())
+        let volume = Volume::<UnitB::UnitLength>::from(&self);
+        Area::<UnitB::UnitLength>::from(
+            volume.f64() / Length::<UnitB::UnitLength>::from(&other).f64(),
+        )
+    }
+}
+
+impl<V, T> Div<Time<T>> for Volume<V>
+where
+    V: VolumeUnit,
+    T: TimeUnit,
+{
+    type Output = VolumeRate<V, T>;
+
+    fn div(self, other: Time<T>) -> Self::Output {
+        VolumeRate::<V, T>::from(self.v.0 / other.f64())
+    }
+}
+
+impl<V, M, L> Mul<Density<M, L>> for Volume<V>
+where
+    V: VolumeUnit,
+    M: MassUnit,
+    L: LengthUnit,
+{
+    type Output = Mass<M>;
+
+    fn mul(self, rhs: Density<M, L>) -> Self::Output {
+        rhs * self
     }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::{feet, feet2, meters, meters2, scalar};
+    use crate::{
+        feet, feet2, feet3, liters, meters, meters2, meters3, scalar, us_gallons, Feet, Meters,
+        Volume,
+    };
     use approx::assert_abs_diff_eq;
 
+    #[test]
+    fn test_volume_conversion() {
+        let ft3 = Volume::<Feet>::from(1);
+        assert_abs_diff_eq!(
+            Volume::<Meters>::from(&ft3),
+            Volume::<Meters>::from(0.028_316_846_592),
+            epsilon = 0.000_000_000_001
+        );
+    }
+
     #[test]
     fn test_meters_to_feet() {
         let ft = feet2!(1);
@@ -94,4 +168,30 @@ mod test {
         println!("m: {m}");
         assert_abs_diff_eq!(m, meters!(1.312_335), epsilon = 0.000_001);
     }
+
+    #[test]
+    fn test_volume_units() {
+        let l = liters!(meters3!(1));
+        println!("{l}");
+        assert_abs_diff_eq!(l, liters!(1_000), epsilon = 0.000_000_001);
+        assert_abs_diff_eq!(
+            liters!(us_gallons!(1)),
+            liters!(3.785_411_784),
+            epsilon = 0.000_000_001
+        );
+        assert_abs_diff_eq!(
+            feet3!(us_gallons!(7.480_519)),
+            feet3!(1),
+            epsilon = 0.000_001
+        );
+        assert_abs_diff_eq!(liters!(1) / meters!(1), meters2!(0.001));
+    }
+
+    #[test]
+    fn test_volume_dyn() {
+        let d = liters!(1).as_dyn() / meters2!(1).as_dyn();
+        assert_abs_diff_eq!(d.f64(), 0.001);
+        let d = us_gallons!(1).as_dyn() / feet2!(1).as_dyn();
+        assert_abs_diff_eq!(d.f64(), f64::from(feet3!(us_gallons!(1))));
+    }
 }
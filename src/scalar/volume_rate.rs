@@ -0,0 +1,174 @@
+use crate::{
+    impl_value_type_conversions, supports_absdiffeq, supports_cancellation, supports_quantity_ops,
+    supports_scalar_ops, supports_shift_ops, supports_value_type_conversion, Density, DynamicUnits,
+    LengthUnit, MassRate, MassUnit, Time, TimeUnit, Volume, VolumeUnit,
+};
+use ordered_float::OrderedFloat;
+use std::{fmt, fmt::Debug, marker::PhantomData, ops::Mul};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct VolumeRate<UnitVolume: VolumeUnit, UnitTime: TimeUnit> {
+    v: OrderedFloat<f64>,
+    phantom_1: PhantomData<UnitVolume>,
+    phantom_2: PhantomData<UnitTime>,
+}
+supports_quantity_ops!(VolumeRate<A, B>, VolumeUnit, TimeUnit);
+supports_shift_ops!(VolumeRate<A1, B1>, VolumeRate<A2, B2>, VolumeUnit, TimeUnit);
+supports_scalar_ops!(VolumeRate<A, B>, VolumeUnit, TimeUnit);
+supports_cancellation!(VolumeRate<A1, B1>, VolumeRate<A2, B2>, VolumeUnit, TimeUnit);
+supports_absdiffeq!(VolumeRate<A, B>, VolumeUnit, TimeUnit);
+supports_value_type_conversion!(VolumeRate<A, B>, VolumeUnit, TimeUnit, impl_value_type_conversions);
+
+impl<V, T> fmt::Display for VolumeRate<V, T>
+where
+    V: VolumeUnit,
+    T: TimeUnit,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.v.0, f)?;
+        write!(
+            f,
+            "{}{}/{}",
+            V::UNIT_SHORT_NAME,
+            V::VOLUME_SUFFIX,
+            T::UNIT_SHORT_NAME
+        )
+    }
+}
+
+impl<'a, VA, TA, VB, TB> From<&'a VolumeRate<VA, TA>> for VolumeRate<VB, TB>
+where
+    VA: VolumeUnit,
+    TA: TimeUnit,
+    VB: VolumeUnit,
+    TB: TimeUnit,
+{
+    fn from(v: &'a VolumeRate<VA, TA>) -> Self {
+        let volume_ratio = VA::CUBIC_METERS_IN_UNIT / VB::CUBIC_METERS_IN_UNIT;
+        let time_ratio = TB::SECONDS_IN_UNIT / TA::SECONDS_IN_UNIT;
+        Self {
+            v: v.v * volume_ratio * time_ratio,
+            phantom_1: PhantomData,
+            phantom_2: PhantomData,
+        }
+    }
+}
+
+impl<V, T> VolumeRate<V, T>
+where
+    V: VolumeUnit,
+    T: TimeUnit,
+{
+    pub fn as_dyn(&self) -> DynamicUnits {
+        DynamicUnits::new3o1::<V::UnitLength, V::UnitLength, V::UnitLength, T>(
+            self.v * V::LENGTH_CUBED_IN_UNIT,
+        )
+    }
+}
+
+impl<V, T> From<DynamicUnits> for VolumeRate<V, T>
+where
+    V: VolumeUnit,
+    T: TimeUnit,
+{
+    fn from(v: DynamicUnits) -> Self {
+        let f = v.ordered_float();
+        v.assert_units_equal(DynamicUnits::new3o1::<
+            V::UnitLength,
+            V::UnitLength,
+            V::UnitLength,
+            T,
+        >(0f64.into()));
+        Self {
+            v: f / V::LENGTH_CUBED_IN_UNIT,
+            phantom_1: PhantomData,
+            phantom_2: PhantomData,
+        }
+    }
+}
+
+impl<VA, TA, TB> Mul<Time<TB>> for VolumeRate<VA, TA>
+where
+    VA: VolumeUnit,
+    TA: TimeUnit,
+    TB: TimeUnit,
+{
+    type Output = Volume<VA>;
+
+    fn mul(self, other: Time<TB>) -> Self::Output {
+        Volume::<VA>::from(self.v.0 * Time::<TA>::from(&other).f64())
+    }
+}
+
+impl<VA, TA, MB, LB> Mul<Density<MB, LB>> for VolumeRate<VA, TA>
+where
+    VA: VolumeUnit,
+    TA: TimeUnit,
+    MB: MassUnit,
+    LB: LengthUnit,
+{
+    type Output = MassRate<MB, TA>;
+
+    fn mul(self, other: Density<MB, LB>) -> Self::Output {
+        let rate = VolumeRate::<LB, TA>::from(&self);
+        MassRate::<MB, TA>::from(rate.f64() * other.f64())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        feet3, feet3_per_second, kilograms_per_meter3, kilograms_per_second, liters,
+        liters_per_minute, meters3, meters3_per_second, minutes, scalar, seconds,
+        us_gallons_per_minute, Liters, Meters, Minutes, UsGallons, VolumeRate,
+    };
+    use approx::assert_abs_diff_eq;
+
+    #[test]
+    fn test_volume_rate() {
+        let lpm = liters_per_minute!(60_000);
+        println!("{lpm}");
+        println!("{}", meters3_per_second!(lpm));
+        assert_abs_diff_eq!(meters3_per_second!(lpm), meters3_per_second!(1));
+        assert_abs_diff_eq!(
+            us_gallons_per_minute!(feet3_per_second!(1)),
+            us_gallons_per_minute!(448.831_169),
+            epsilon = 0.000_001
+        );
+        assert_abs_diff_eq!(meters3_per_second!(2) * scalar!(2), meters3_per_second!(4));
+    }
+
+    #[test]
+    fn test_volume_rate_time() {
+        let q = meters3!(10) / seconds!(5);
+        assert_abs_diff_eq!(q, meters3_per_second!(2));
+        assert_abs_diff_eq!(liters_per_minute!(10) * minutes!(3), liters!(30));
+        assert_abs_diff_eq!(
+            meters3!(liters_per_minute!(10) * seconds!(6)),
+            meters3!(0.001),
+            epsilon = 0.000_000_001
+        );
+    }
+
+    #[test]
+    fn test_volume_rate_mass_rate() {
+        let fuel = liters_per_minute!(60) * kilograms_per_meter3!(800);
+        assert_abs_diff_eq!(kilograms_per_second!(fuel), kilograms_per_second!(0.8));
+    }
+
+    #[test]
+    fn test_volume_rate_dyn() {
+        let q = liters_per_minute!(60_000).as_dyn();
+        assert_abs_diff_eq!(q.f64(), 60.);
+        let lpm: VolumeRate<Liters, Minutes> = q.into();
+        assert_abs_diff_eq!(lpm, liters_per_minute!(60_000), epsilon = 1e-9);
+        let q: VolumeRate<Meters, Minutes> = liters_per_minute!(1_000).as_dyn().into();
+        assert_abs_diff_eq!(q.f64(), 1., epsilon = 1e-12);
+        let gpm: VolumeRate<UsGallons, Minutes> = (feet3!(2) / minutes!(1)).as_dyn().into();
+        assert_abs_diff_eq!(
+            gpm,
+            us_gallons_per_minute!(feet3_per_second!(2. / 60.)),
+            epsilon = 1e-9
+        );
+    }
+}
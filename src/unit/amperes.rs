@@ -0,0 +1,19 @@
+use crate::{CurrentUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Amperes;
+impl Unit for Amperes {
+    const UNIT_NAME: &'static str = "amperes";
+    const UNIT_SHORT_NAME: &'static str = "A";
+    const UNIT_SUFFIX: &'static str = "A";
+}
+impl CurrentUnit for Amperes {
+    const AMPERES_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! amperes {
+    ($num:expr) => {
+        $crate::Current::<$crate::Amperes>::from(&$num)
+    };
+}
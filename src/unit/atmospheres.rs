@@ -0,0 +1,19 @@
+use crate::{PressureUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Atmospheres;
+impl Unit for Atmospheres {
+    const UNIT_NAME: &'static str = "atmospheres";
+    const UNIT_SHORT_NAME: &'static str = "atm";
+    const UNIT_SUFFIX: &'static str = "atm";
+}
+impl PressureUnit for Atmospheres {
+    const PASCALS_IN_UNIT: f64 = 101_325.0;
+}
+
+#[macro_export]
+macro_rules! atmospheres {
+    ($num:expr) => {
+        $crate::Pressure::<$crate::Atmospheres>::from(&$num)
+    };
+}
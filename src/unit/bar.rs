@@ -0,0 +1,19 @@
+use crate::{PressureUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Bar;
+impl Unit for Bar {
+    const UNIT_NAME: &'static str = "bar";
+    const UNIT_SHORT_NAME: &'static str = "bar";
+    const UNIT_SUFFIX: &'static str = "bar";
+}
+impl PressureUnit for Bar {
+    const PASCALS_IN_UNIT: f64 = 100_000.0;
+}
+
+#[macro_export]
+macro_rules! bar {
+    ($num:expr) => {
+        $crate::Pressure::<$crate::Bar>::from(&$num)
+    };
+}
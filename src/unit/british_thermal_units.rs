@@ -0,0 +1,27 @@
+use crate::{EnergyUnit, Unit};
+
+// International Table BTU
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct BritishThermalUnits;
+impl Unit for BritishThermalUnits {
+    const UNIT_NAME: &'static str = "british thermal units";
+    const UNIT_SHORT_NAME: &'static str = "BTU";
+    const UNIT_SUFFIX: &'static str = "BTU";
+}
+impl EnergyUnit for BritishThermalUnits {
+    const JOULES_IN_UNIT: f64 = 1_055.055_852_62;
+}
+
+#[macro_export]
+macro_rules! british_thermal_units {
+    ($num:expr) => {
+        $crate::Energy::<$crate::BritishThermalUnits>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! btu {
+    ($num:expr) => {
+        $crate::Energy::<$crate::BritishThermalUnits>::from(&$num)
+    };
+}
@@ -8,6 +8,8 @@ impl Unit for Celsius {
     const UNIT_SUFFIX: &'static str = "°C";
 }
 impl TemperatureUnit for Celsius {
+    const KELVIN_IN_UNIT: f64 = 1.0;
+
     fn convert_to_kelvin(degrees_in: f64) -> f64 {
         degrees_in + 273.15
     }
@@ -22,3 +24,10 @@ macro_rules! celsius {
         $crate::Temperature::<$crate::Celsius>::from(&$num)
     };
 }
+
+#[macro_export]
+macro_rules! celsius_delta {
+    ($num:expr) => {
+        $crate::TemperatureDelta::<$crate::Celsius>::from(&$num)
+    };
+}
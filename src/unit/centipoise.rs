@@ -0,0 +1,19 @@
+use crate::{DynamicViscosityUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Centipoise;
+impl Unit for Centipoise {
+    const UNIT_NAME: &'static str = "centipoise";
+    const UNIT_SHORT_NAME: &'static str = "cP";
+    const UNIT_SUFFIX: &'static str = "cP";
+}
+impl DynamicViscosityUnit for Centipoise {
+    const PASCAL_SECONDS_IN_UNIT: f64 = 0.001;
+}
+
+#[macro_export]
+macro_rules! centipoise {
+    ($num:expr) => {
+        $crate::DynamicViscosity::<$crate::Centipoise>::from(&$num)
+    };
+}
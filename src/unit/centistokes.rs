@@ -0,0 +1,19 @@
+use crate::{KinematicViscosityUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Centistokes;
+impl Unit for Centistokes {
+    const UNIT_NAME: &'static str = "centistokes";
+    const UNIT_SHORT_NAME: &'static str = "cSt";
+    const UNIT_SUFFIX: &'static str = "cSt";
+}
+impl KinematicViscosityUnit for Centistokes {
+    const SQUARE_METERS_PER_SECOND_IN_UNIT: f64 = 0.000_001;
+}
+
+#[macro_export]
+macro_rules! centistokes {
+    ($num:expr) => {
+        $crate::KinematicViscosity::<$crate::Centistokes>::from(&$num)
+    };
+}
@@ -0,0 +1,19 @@
+use crate::{ChargeUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Coulombs;
+impl Unit for Coulombs {
+    const UNIT_NAME: &'static str = "coulombs";
+    const UNIT_SHORT_NAME: &'static str = "C";
+    const UNIT_SUFFIX: &'static str = "C";
+}
+impl ChargeUnit for Coulombs {
+    const COULOMBS_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! coulombs {
+    ($num:expr) => {
+        $crate::Charge::<$crate::Coulombs>::from(&$num)
+    };
+}
@@ -0,0 +1,19 @@
+use crate::{FrequencyUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct CyclesPerMinute;
+impl Unit for CyclesPerMinute {
+    const UNIT_NAME: &'static str = "cycles per minute";
+    const UNIT_SHORT_NAME: &'static str = "cpm";
+    const UNIT_SUFFIX: &'static str = "cpm";
+}
+impl FrequencyUnit for CyclesPerMinute {
+    const HERTZ_IN_UNIT: f64 = 1. / 60.;
+}
+
+#[macro_export]
+macro_rules! cycles_per_minute {
+    ($num:expr) => {
+        $crate::Frequency::<$crate::CyclesPerMinute>::from(&$num)
+    };
+}
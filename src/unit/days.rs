@@ -0,0 +1,19 @@
+use crate::{TimeUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Days;
+impl Unit for Days {
+    const UNIT_NAME: &'static str = "days";
+    const UNIT_SHORT_NAME: &'static str = "d";
+    const UNIT_SUFFIX: &'static str = "d";
+}
+impl TimeUnit for Days {
+    const SECONDS_IN_UNIT: f64 = 86_400.;
+}
+
+#[macro_export]
+macro_rules! days {
+    ($num:expr) => {
+        $crate::Time::<$crate::Days>::from(&$num)
+    };
+}
@@ -0,0 +1,250 @@
+/// Declare a new unit: the unit struct, its `Unit` impl, the impl of its kind trait
+/// (`LengthUnit`, `MassUnit`, etc), and, optionally, `#[macro_export]` constructor
+/// macros in the style of `meters!` or `meters_per_second!`.
+///
+/// The suffix defaults to the short name if not given. Constructor macros need the
+/// full path of the new unit from the root of its crate, starting with `crate`. Each
+/// one names a quantity type from this crate, followed by any further unit parameters
+/// that quantity needs after the new unit: either the name of a unit from this crate
+/// or the `crate` path of a unit from yours. The generated macros do not need the
+/// units to be imported where they are used.
+///
+/// Other crates use the generated macros by path, as `your_crate::furlongs!`. Within
+/// the defining crate, rustc does not allow `#[macro_export]` macros produced by
+/// another macro to be referred to by path, so they are only available by name:
+/// after the `define_unit!` in the same module, or elsewhere through `#[macro_use]`
+/// on the modules that contain it.
+///
+/// ```
+/// use absolute_unit::prelude::*;
+///
+/// absolute_unit::define_unit! {
+///     /// One eighth of a mile.
+///     pub Furlongs {
+///         name: "furlongs",
+///         short_name: "fur",
+///     }
+///     impl LengthUnit {
+///         const METERS_IN_UNIT: f64 = 201.168;
+///     }
+///     macros(crate::Furlongs) {
+///         furlongs => Length,
+///         furlongs_per_second => Velocity<Seconds>,
+///     }
+/// }
+///
+/// fn main() {
+///     assert_eq!(format!("{}", furlongs!(2)), "2fur");
+///     assert_eq!(meters!(furlongs!(8)).f64().round(), 1_609.);
+///     assert_eq!(furlongs_per_second!(meters_per_second!(201.168)).f64(), 1.);
+/// }
+/// ```
+///
+/// ```
+/// #[macro_use]
+/// mod units {
+///     use absolute_unit::prelude::*;
+///
+///     absolute_unit::define_unit! {
+///         pub Furlongs {
+///             name: "furlongs",
+///             short_name: "fur",
+///         }
+///         impl LengthUnit {
+///             const METERS_IN_UNIT: f64 = 201.168;
+///         }
+///         macros(crate::units::Furlongs) {
+///             furlongs => Length,
+///         }
+///     }
+/// }
+///
+/// mod race {
+///     pub fn distance() -> absolute_unit::Length<crate::units::Furlongs> {
+///         furlongs!(absolute_unit::meters!(2_414.016))
+///     }
+/// }
+///
+/// fn main() {
+///     assert_eq!(race::distance().f64().round(), 12.);
+/// }
+/// ```
+#[macro_export]
+macro_rules! define_unit {
+    (
+        $(#[$meta:meta])*
+        $vis:vis $Unit:ident {
+            name: $name:literal,
+            short_name: $short_name:literal
+            $(, suffix: $suffix:literal)? $(,)?
+        }
+        impl $Kind:path { $($body:tt)* }
+        $(macros($krate:ident $(:: $unit_path:ident)+) { $($constructors:tt)* })?
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+        $vis struct $Unit;
+        impl $crate::Unit for $Unit {
+            const UNIT_NAME: &'static str = $name;
+            const UNIT_SHORT_NAME: &'static str = $short_name;
+            const UNIT_SUFFIX: &'static str = $crate::define_unit!(@suffix $short_name $(, $suffix)?);
+        }
+        impl $Kind for $Unit {
+            $($body)*
+        }
+        $(
+            $crate::define_unit!(
+                @constructors ($) [$krate $(:: $unit_path)+] $($constructors)*
+            );
+        )?
+    };
+
+    (@suffix $short_name:literal) => {
+        $short_name
+    };
+
+    (@suffix $short_name:literal, $suffix:literal) => {
+        $suffix
+    };
+
+    (@constructors ($d:tt) [$($unit:tt)*]) => {};
+
+    (
+        @constructors ($d:tt) [$krate:ident $(:: $unit_path:ident)+]
+        $macro_name:ident => $Quantity:ident $(< $($($Param:ident)::+),+ >)?
+        $(, $($rest:tt)*)?
+    ) => {
+        $crate::define_unit!(
+            @constructor ($d) $macro_name $Quantity [$d $krate $(:: $unit_path)+]
+            $($($($Param)::+),+)?
+        );
+        $crate::define_unit!(@constructors ($d) [$krate $(:: $unit_path)+] $($($rest)*)?);
+    };
+
+    // Constructor macros are expanded wherever they are used, so every unit is named by a path
+    // that resolves from anywhere: units in the defining crate through that crate's `$crate`,
+    // and built-in units through ours. The `$` is passed in so that it can be written into the
+    // generated macro, and the caller's own `crate` token is kept so that the `$crate` it forms
+    // refers to the caller's crate.
+    (@constructor ($d:tt) $macro_name:ident $Quantity:ident [$($units:tt)*]) => {
+        #[macro_export]
+        macro_rules! $macro_name {
+            ($d num:expr) => {
+                $crate::$Quantity::<$($units)*>::from(&$d num)
+            };
+        }
+    };
+
+    (
+        @constructor ($d:tt) $macro_name:ident $Quantity:ident [$($units:tt)*]
+        $krate:ident $(:: $param_path:ident)+ $(, $($rest:tt)*)?
+    ) => {
+        $crate::define_unit!(
+            @constructor ($d) $macro_name $Quantity [$($units)*, $d $krate $(:: $param_path)+]
+            $($($rest)*)?
+        );
+    };
+
+    (
+        @constructor ($d:tt) $macro_name:ident $Quantity:ident [$($units:tt)*]
+        $Param:ident $(, $($rest:tt)*)?
+    ) => {
+        $crate::define_unit!(
+            @constructor ($d) $macro_name $Quantity [$($units)*, $crate::$Param]
+            $($($rest)*)?
+        );
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        meters_per_second, meters_per_second2, newtons, seconds, tonnes, Force, ForceUnit,
+        LengthUnit, Meters, Newtons, Seconds, Time, TimeUnit, Tonnes, Unit,
+    };
+    use approx::assert_abs_diff_eq;
+
+    define_unit! {
+        pub(crate) Fortnights {
+            name: "fortnights",
+            short_name: "ftn",
+            suffix: " fortnights",
+        }
+        impl TimeUnit {
+            const SECONDS_IN_UNIT: f64 = 14. * 24. * 60. * 60.;
+        }
+        macros(crate::unit::define::test::Fortnights) {
+            fortnights => Time,
+        }
+    }
+
+    define_unit! {
+        pub(crate) Furlongs {
+            name: "furlongs",
+            short_name: "fur",
+        }
+        impl LengthUnit {
+            const METERS_IN_UNIT: f64 = 201.168;
+        }
+        macros(crate::unit::define::test::Furlongs) {
+            furlongs_per_second => Velocity<Seconds>,
+            furlongs_per_fortnight => Velocity<crate::unit::define::test::Fortnights>,
+        }
+    }
+
+    define_unit! {
+        Sthenes {
+            name: "sthenes",
+            short_name: "sn",
+        }
+        impl ForceUnit {
+            const NEWTONS_IN_UNIT: f64 = 1_000.;
+
+            type UnitMass = Tonnes;
+            type UnitLength = Meters;
+            type UnitTime = Seconds;
+        }
+    }
+
+    #[test]
+    fn test_define_unit() {
+        assert_eq!(Fortnights::UNIT_NAME, "fortnights");
+        assert_eq!(Fortnights::UNIT_SHORT_NAME, "ftn");
+        assert_eq!(Fortnights::UNIT_SUFFIX, " fortnights");
+        assert_eq!(format!("{}", fortnights!(2)), "2.0000ftn");
+        assert_abs_diff_eq!(
+            seconds!(fortnights!(1)),
+            seconds!(1_209_600),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(Time::<Fortnights>::from(&seconds!(604_800)).f64(), 0.5);
+    }
+
+    #[test]
+    fn test_define_unit_with_associated_types() {
+        assert_eq!(Sthenes::UNIT_SUFFIX, "sn");
+        let f = Force::<Sthenes>::from(&newtons!(2_500));
+        assert_abs_diff_eq!(f.f64(), 2.5);
+        assert_abs_diff_eq!(newtons!(f), newtons!(2_500));
+        let n: Force<Newtons> = f.as_dyn().into();
+        assert_abs_diff_eq!(n, newtons!(2_500));
+        let f: Force<Sthenes> = newtons!(500).as_dyn().into();
+        assert_abs_diff_eq!(f.f64(), 0.5);
+        assert_abs_diff_eq!(
+            Force::<Sthenes>::from(1) / tonnes!(1),
+            meters_per_second2!(1),
+            epsilon = 1e-12
+        );
+    }
+
+    #[test]
+    fn test_define_unit_constructor_params() {
+        let v = furlongs_per_fortnight!(furlongs_per_second!(1));
+        assert_abs_diff_eq!(v.f64(), 1_209_600., epsilon = 1e-6);
+        assert_abs_diff_eq!(
+            meters_per_second!(furlongs_per_second!(1)),
+            meters_per_second!(201.168),
+            epsilon = 1e-12
+        );
+    }
+}
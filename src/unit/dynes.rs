@@ -0,0 +1,23 @@
+use crate::{Centimeters, ForceUnit, Grams, Seconds, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Dynes;
+impl Unit for Dynes {
+    const UNIT_NAME: &'static str = "dynes";
+    const UNIT_SHORT_NAME: &'static str = "dyn";
+    const UNIT_SUFFIX: &'static str = "dyn";
+}
+impl ForceUnit for Dynes {
+    const NEWTONS_IN_UNIT: f64 = 0.000_01;
+
+    type UnitMass = Grams;
+    type UnitLength = Centimeters;
+    type UnitTime = Seconds;
+}
+
+#[macro_export]
+macro_rules! dynes {
+    ($num:expr) => {
+        $crate::Force::<$crate::Dynes>::from(&$num)
+    };
+}
@@ -0,0 +1,40 @@
+use crate::{MassUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct EarthMasses;
+impl Unit for EarthMasses {
+    const UNIT_NAME: &'static str = "earth masses";
+    const UNIT_SHORT_NAME: &'static str = "M⊕";
+    const UNIT_SUFFIX: &'static str = "M⊕";
+}
+impl MassUnit for EarthMasses {
+    const GRAMS_IN_UNIT: f64 = 5.972_2e27;
+}
+
+#[macro_export]
+macro_rules! earth_masses {
+    ($num:expr) => {
+        $crate::Mass::<$crate::EarthMasses>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! earth_masses_per_parsec3 {
+    ($num:expr) => {
+        $crate::Density::<$crate::EarthMasses, $crate::Parsecs>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! earth_masses_per_second {
+    ($num:expr) => {
+        $crate::MassRate::<$crate::EarthMasses, $crate::Seconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! earth_masses_per_julian_year {
+    ($num:expr) => {
+        $crate::MassRate::<$crate::EarthMasses, $crate::JulianYears>::from(&$num)
+    };
+}
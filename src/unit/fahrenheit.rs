@@ -8,6 +8,8 @@ impl Unit for Fahrenheit {
     const UNIT_SUFFIX: &'static str = "°F";
 }
 impl TemperatureUnit for Fahrenheit {
+    const KELVIN_IN_UNIT: f64 = 5. / 9.;
+
     fn convert_to_kelvin(degrees_in: f64) -> f64 {
         (degrees_in - 32.) * 5. / 9. + 273.15
     }
@@ -22,3 +24,10 @@ macro_rules! fahrenheit {
         $crate::Temperature::<$crate::Fahrenheit>::from(&$num)
     };
 }
+
+#[macro_export]
+macro_rules! fahrenheit_delta {
+    ($num:expr) => {
+        $crate::TemperatureDelta::<$crate::Fahrenheit>::from(&$num)
+    };
+}
@@ -0,0 +1,19 @@
+use crate::{CapacitanceUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Farads;
+impl Unit for Farads {
+    const UNIT_NAME: &'static str = "farads";
+    const UNIT_SHORT_NAME: &'static str = "F";
+    const UNIT_SUFFIX: &'static str = "F";
+}
+impl CapacitanceUnit for Farads {
+    const FARADS_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! farads {
+    ($num:expr) => {
+        $crate::Capacitance::<$crate::Farads>::from(&$num)
+    };
+}
@@ -0,0 +1,40 @@
+use crate::{LengthUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Fathoms;
+impl Unit for Fathoms {
+    const UNIT_NAME: &'static str = "fathoms";
+    const UNIT_SHORT_NAME: &'static str = "ftm";
+    const UNIT_SUFFIX: &'static str = "ftm";
+}
+impl LengthUnit for Fathoms {
+    const METERS_IN_UNIT: f64 = 1.828_8;
+}
+
+#[macro_export]
+macro_rules! fathoms {
+    ($num:expr) => {
+        $crate::Length::<$crate::Fathoms>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! fathoms2 {
+    ($num:expr) => {
+        $crate::Area::<$crate::Fathoms>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! fathoms3 {
+    ($num:expr) => {
+        $crate::Volume::<$crate::Fathoms>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! fathoms_per_second {
+    ($num:expr) => {
+        $crate::Velocity::<$crate::Fathoms, $crate::Seconds>::from(&$num)
+    };
+}
@@ -25,6 +25,20 @@ macro_rules! feet2 {
     };
 }
 
+#[macro_export]
+macro_rules! feet3 {
+    ($num:expr) => {
+        $crate::Volume::<$crate::Feet>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! feet3_per_second {
+    ($num:expr) => {
+        $crate::VolumeRate::<$crate::Feet, $crate::Seconds>::from(&$num)
+    };
+}
+
 #[macro_export]
 macro_rules! feet_per_second {
     ($num:expr) => {
@@ -38,3 +52,10 @@ macro_rules! feet_per_second2 {
         $crate::Acceleration::<$crate::Feet, $crate::Seconds>::from(&$num)
     };
 }
+
+#[macro_export]
+macro_rules! feet_per_second3 {
+    ($num:expr) => {
+        $crate::Jerk::<$crate::Feet, $crate::Seconds>::from(&$num)
+    };
+}
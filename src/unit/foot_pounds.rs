@@ -0,0 +1,19 @@
+use crate::{EnergyUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct FootPounds;
+impl Unit for FootPounds {
+    const UNIT_NAME: &'static str = "foot-pounds";
+    const UNIT_SHORT_NAME: &'static str = "ft*lbf";
+    const UNIT_SUFFIX: &'static str = "ft*lbf";
+}
+impl EnergyUnit for FootPounds {
+    const JOULES_IN_UNIT: f64 = 1.355_817_948_331_400_4;
+}
+
+#[macro_export]
+macro_rules! foot_pounds {
+    ($num:expr) => {
+        $crate::Energy::<$crate::FootPounds>::from(&$num)
+    };
+}
@@ -0,0 +1,19 @@
+use crate::{PowerUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct FootPoundsPerSecond;
+impl Unit for FootPoundsPerSecond {
+    const UNIT_NAME: &'static str = "foot-pounds per second";
+    const UNIT_SHORT_NAME: &'static str = "ft*lbf/s";
+    const UNIT_SUFFIX: &'static str = "ft*lbf/s";
+}
+impl PowerUnit for FootPoundsPerSecond {
+    const WATTS_IN_UNIT: f64 = 1.355_817_948_331_400_4;
+}
+
+#[macro_export]
+macro_rules! foot_pounds_per_second {
+    ($num:expr) => {
+        $crate::Power::<$crate::FootPoundsPerSecond>::from(&$num)
+    };
+}
@@ -0,0 +1,19 @@
+use crate::{SpecificGasConstantUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct FootPoundsPerSlugRankine;
+impl Unit for FootPoundsPerSlugRankine {
+    const UNIT_NAME: &'static str = "foot-pounds per slug rankine";
+    const UNIT_SHORT_NAME: &'static str = "ft*lbf/(slug*°R)";
+    const UNIT_SUFFIX: &'static str = "ft*lbf/(slug*°R)";
+}
+impl SpecificGasConstantUnit for FootPoundsPerSlugRankine {
+    const JOULES_PER_KILOGRAM_KELVIN_IN_UNIT: f64 = 0.167_225_472;
+}
+
+#[macro_export]
+macro_rules! foot_pounds_per_slug_rankine {
+    ($num:expr) => {
+        $crate::SpecificGasConstant::<$crate::FootPoundsPerSlugRankine>::from(&$num)
+    };
+}
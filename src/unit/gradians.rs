@@ -0,0 +1,34 @@
+use crate::{AngleUnit, Unit};
+use std::f64::consts::PI;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Gradians;
+impl Unit for Gradians {
+    const UNIT_NAME: &'static str = "gradians";
+    const UNIT_SHORT_NAME: &'static str = "grad";
+    const UNIT_SUFFIX: &'static str = "ᵍ";
+}
+impl AngleUnit for Gradians {
+    const RADIANS_IN_UNIT: f64 = PI / 200f64;
+}
+
+#[macro_export]
+macro_rules! gradians {
+    ($num:expr) => {
+        $crate::Angle::<$crate::Gradians>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! gradians_per_second {
+    ($num:expr) => {
+        $crate::AngularVelocity::<$crate::Gradians, $crate::Seconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! gradians_per_second2 {
+    ($num:expr) => {
+        $crate::AngularAcceleration::<$crate::Gradians, $crate::Seconds>::from(&$num)
+    };
+}
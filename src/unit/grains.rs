@@ -0,0 +1,40 @@
+use crate::{MassUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Grains;
+impl Unit for Grains {
+    const UNIT_NAME: &'static str = "grains";
+    const UNIT_SHORT_NAME: &'static str = "gr";
+    const UNIT_SUFFIX: &'static str = "gr";
+}
+impl MassUnit for Grains {
+    const GRAMS_IN_UNIT: f64 = 0.064_798_91;
+}
+
+#[macro_export]
+macro_rules! grains {
+    ($num:expr) => {
+        $crate::Mass::<$crate::Grains>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! grains_per_foot3 {
+    ($num:expr) => {
+        $crate::Density::<$crate::Grains, $crate::Feet>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! grains_per_second {
+    ($num:expr) => {
+        $crate::MassRate::<$crate::Grains, $crate::Seconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! grains_per_hour {
+    ($num:expr) => {
+        $crate::MassRate::<$crate::Grains, $crate::Hours>::from(&$num)
+    };
+}
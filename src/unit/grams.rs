@@ -0,0 +1,40 @@
+use crate::{MassUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Grams;
+impl Unit for Grams {
+    const UNIT_NAME: &'static str = "grams";
+    const UNIT_SHORT_NAME: &'static str = "g";
+    const UNIT_SUFFIX: &'static str = "g";
+}
+impl MassUnit for Grams {
+    const GRAMS_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! grams {
+    ($num:expr) => {
+        $crate::Mass::<$crate::Grams>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! grams_per_centimeter3 {
+    ($num:expr) => {
+        $crate::Density::<$crate::Grams, $crate::Centimeters>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! grams_per_second {
+    ($num:expr) => {
+        $crate::MassRate::<$crate::Grams, $crate::Seconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! grams_per_hour {
+    ($num:expr) => {
+        $crate::MassRate::<$crate::Grams, $crate::Hours>::from(&$num)
+    };
+}
@@ -0,0 +1,19 @@
+use crate::{TsfcUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct GramsPerKilonewtonSecond;
+impl Unit for GramsPerKilonewtonSecond {
+    const UNIT_NAME: &'static str = "grams per kilonewton-second";
+    const UNIT_SHORT_NAME: &'static str = "g/(kN*s)";
+    const UNIT_SUFFIX: &'static str = "g/(kN*s)";
+}
+impl TsfcUnit for GramsPerKilonewtonSecond {
+    const KILOGRAMS_PER_NEWTON_SECOND_IN_UNIT: f64 = 0.000_001;
+}
+
+#[macro_export]
+macro_rules! grams_per_kilonewton_second {
+    ($num:expr) => {
+        $crate::Tsfc::<$crate::GramsPerKilonewtonSecond>::from(&$num)
+    };
+}
@@ -0,0 +1,19 @@
+use crate::{MolarMassUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct GramsPerMole;
+impl Unit for GramsPerMole {
+    const UNIT_NAME: &'static str = "grams per mole";
+    const UNIT_SHORT_NAME: &'static str = "g/mol";
+    const UNIT_SUFFIX: &'static str = "g/mol";
+}
+impl MolarMassUnit for GramsPerMole {
+    const KILOGRAMS_PER_MOLE_IN_UNIT: f64 = 0.001;
+}
+
+#[macro_export]
+macro_rules! grams_per_mole {
+    ($num:expr) => {
+        $crate::MolarMass::<$crate::GramsPerMole>::from(&$num)
+    };
+}
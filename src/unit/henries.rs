@@ -0,0 +1,19 @@
+use crate::{InductanceUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Henries;
+impl Unit for Henries {
+    const UNIT_NAME: &'static str = "henries";
+    const UNIT_SHORT_NAME: &'static str = "H";
+    const UNIT_SUFFIX: &'static str = "H";
+}
+impl InductanceUnit for Henries {
+    const HENRIES_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! henries {
+    ($num:expr) => {
+        $crate::Inductance::<$crate::Henries>::from(&$num)
+    };
+}
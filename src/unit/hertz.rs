@@ -0,0 +1,19 @@
+use crate::{FrequencyUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Hertz;
+impl Unit for Hertz {
+    const UNIT_NAME: &'static str = "hertz";
+    const UNIT_SHORT_NAME: &'static str = "Hz";
+    const UNIT_SUFFIX: &'static str = "Hz";
+}
+impl FrequencyUnit for Hertz {
+    const HERTZ_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! hertz {
+    ($num:expr) => {
+        $crate::Frequency::<$crate::Hertz>::from(&$num)
+    };
+}
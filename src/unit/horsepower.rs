@@ -0,0 +1,20 @@
+use crate::{PowerUnit, Unit};
+
+// Mechanical (imperial) horsepower: 550 ft*lbf/s
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Horsepower;
+impl Unit for Horsepower {
+    const UNIT_NAME: &'static str = "horsepower";
+    const UNIT_SHORT_NAME: &'static str = "hp";
+    const UNIT_SUFFIX: &'static str = "hp";
+}
+impl PowerUnit for Horsepower {
+    const WATTS_IN_UNIT: f64 = 745.699_871_582_270_2;
+}
+
+#[macro_export]
+macro_rules! horsepower {
+    ($num:expr) => {
+        $crate::Power::<$crate::Horsepower>::from(&$num)
+    };
+}
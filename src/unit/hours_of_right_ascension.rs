@@ -0,0 +1,34 @@
+use crate::{AngleUnit, Unit};
+use std::f64::consts::PI;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct HoursOfRightAscension;
+impl Unit for HoursOfRightAscension {
+    const UNIT_NAME: &'static str = "hours of right ascension";
+    const UNIT_SHORT_NAME: &'static str = "hRA";
+    const UNIT_SUFFIX: &'static str = "ʰ";
+}
+impl AngleUnit for HoursOfRightAscension {
+    const RADIANS_IN_UNIT: f64 = PI / 12f64;
+}
+
+#[macro_export]
+macro_rules! ra_hours {
+    ($num:expr) => {
+        $crate::Angle::<$crate::HoursOfRightAscension>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! ra_hours_per_second {
+    ($num:expr) => {
+        $crate::AngularVelocity::<$crate::HoursOfRightAscension, $crate::Seconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! ra_hours_per_second2 {
+    ($num:expr) => {
+        $crate::AngularAcceleration::<$crate::HoursOfRightAscension, $crate::Seconds>::from(&$num)
+    };
+}
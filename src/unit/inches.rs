@@ -0,0 +1,40 @@
+use crate::{LengthUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Inches;
+impl Unit for Inches {
+    const UNIT_NAME: &'static str = "inches";
+    const UNIT_SHORT_NAME: &'static str = "in";
+    const UNIT_SUFFIX: &'static str = "\"";
+}
+impl LengthUnit for Inches {
+    const METERS_IN_UNIT: f64 = 0.025_4;
+}
+
+#[macro_export]
+macro_rules! inches {
+    ($num:expr) => {
+        $crate::Length::<$crate::Inches>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! inches2 {
+    ($num:expr) => {
+        $crate::Area::<$crate::Inches>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! inches3 {
+    ($num:expr) => {
+        $crate::Volume::<$crate::Inches>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! inches_per_second {
+    ($num:expr) => {
+        $crate::Velocity::<$crate::Inches, $crate::Seconds>::from(&$num)
+    };
+}
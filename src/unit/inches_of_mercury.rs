@@ -0,0 +1,20 @@
+use crate::{PressureUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct InchesOfMercury;
+impl Unit for InchesOfMercury {
+    const UNIT_NAME: &'static str = "inches of mercury";
+    const UNIT_SHORT_NAME: &'static str = "inHg";
+    const UNIT_SUFFIX: &'static str = "inHg";
+}
+// Conventional: 25.4 millimeters of mercury.
+impl PressureUnit for InchesOfMercury {
+    const PASCALS_IN_UNIT: f64 = 133.322_387_415 * 25.4;
+}
+
+#[macro_export]
+macro_rules! inches_of_mercury {
+    ($num:expr) => {
+        $crate::Pressure::<$crate::InchesOfMercury>::from(&$num)
+    };
+}
@@ -0,0 +1,19 @@
+use crate::{EnergyUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Joules;
+impl Unit for Joules {
+    const UNIT_NAME: &'static str = "joules";
+    const UNIT_SHORT_NAME: &'static str = "J";
+    const UNIT_SUFFIX: &'static str = "J";
+}
+impl EnergyUnit for Joules {
+    const JOULES_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! joules {
+    ($num:expr) => {
+        $crate::Energy::<$crate::Joules>::from(&$num)
+    };
+}
@@ -0,0 +1,19 @@
+use crate::{SpecificGasConstantUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct JoulesPerKilogramKelvin;
+impl Unit for JoulesPerKilogramKelvin {
+    const UNIT_NAME: &'static str = "joules per kilogram kelvin";
+    const UNIT_SHORT_NAME: &'static str = "J/(kg*K)";
+    const UNIT_SUFFIX: &'static str = "J/(kg*K)";
+}
+impl SpecificGasConstantUnit for JoulesPerKilogramKelvin {
+    const JOULES_PER_KILOGRAM_KELVIN_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! joules_per_kilogram_kelvin {
+    ($num:expr) => {
+        $crate::SpecificGasConstant::<$crate::JoulesPerKilogramKelvin>::from(&$num)
+    };
+}
@@ -0,0 +1,20 @@
+use crate::{TimeUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct JulianYears;
+impl Unit for JulianYears {
+    const UNIT_NAME: &'static str = "Julian years";
+    const UNIT_SHORT_NAME: &'static str = "a";
+    const UNIT_SUFFIX: &'static str = "a";
+}
+// 365.25 days of 86,400 seconds.
+impl TimeUnit for JulianYears {
+    const SECONDS_IN_UNIT: f64 = 31_557_600.;
+}
+
+#[macro_export]
+macro_rules! julian_years {
+    ($num:expr) => {
+        $crate::Time::<$crate::JulianYears>::from(&$num)
+    };
+}
@@ -8,6 +8,8 @@ impl Unit for Kelvin {
     const UNIT_SUFFIX: &'static str = "°K";
 }
 impl TemperatureUnit for Kelvin {
+    const KELVIN_IN_UNIT: f64 = 1.0;
+
     fn convert_to_kelvin(degrees_in: f64) -> f64 {
         degrees_in
     }
@@ -22,3 +24,10 @@ macro_rules! kelvin {
         $crate::Temperature::<$crate::Kelvin>::from(&$num)
     };
 }
+
+#[macro_export]
+macro_rules! kelvin_delta {
+    ($num:expr) => {
+        $crate::TemperatureDelta::<$crate::Kelvin>::from(&$num)
+    };
+}
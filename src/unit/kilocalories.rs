@@ -0,0 +1,20 @@
+use crate::{EnergyUnit, Unit};
+
+// Thermochemical (food) kilocalories
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Kilocalories;
+impl Unit for Kilocalories {
+    const UNIT_NAME: &'static str = "kilocalories";
+    const UNIT_SHORT_NAME: &'static str = "kcal";
+    const UNIT_SUFFIX: &'static str = "kcal";
+}
+impl EnergyUnit for Kilocalories {
+    const JOULES_IN_UNIT: f64 = 4_184.0;
+}
+
+#[macro_export]
+macro_rules! kilocalories {
+    ($num:expr) => {
+        $crate::Energy::<$crate::Kilocalories>::from(&$num)
+    };
+}
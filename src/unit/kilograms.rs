@@ -1,15 +1,6 @@
-use crate::{MassUnit, Unit};
+use crate::{Grams, Kilo, Prefixed};
 
-#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
-pub struct Kilograms;
-impl Unit for Kilograms {
-    const UNIT_NAME: &'static str = "kilograms";
-    const UNIT_SHORT_NAME: &'static str = "kg";
-    const UNIT_SUFFIX: &'static str = "kg";
-}
-impl MassUnit for Kilograms {
-    const GRAMS_IN_UNIT: f64 = 1_000.0;
-}
+pub type Kilograms = Prefixed<Kilo, Grams>;
 
 #[macro_export]
 macro_rules! kilograms {
@@ -25,6 +16,20 @@ macro_rules! kilograms_per_meter3 {
     };
 }
 
+#[macro_export]
+macro_rules! kilograms_per_meter2 {
+    ($num:expr) => {
+        $crate::ArealDensity::<$crate::Kilograms, $crate::Meters>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! kilograms_per_meter {
+    ($num:expr) => {
+        $crate::LinearDensity::<$crate::Kilograms, $crate::Meters>::from(&$num)
+    };
+}
+
 #[macro_export]
 macro_rules! kilograms_meter2 {
     ($num:expr) => {
@@ -32,9 +37,23 @@ macro_rules! kilograms_meter2 {
     };
 }
 
+#[macro_export]
+macro_rules! kilograms_meter2_per_second {
+    ($num:expr) => {
+        $crate::AngularMomentum::<$crate::Kilograms, $crate::Meters, $crate::Seconds>::from(&$num)
+    };
+}
+
 #[macro_export]
 macro_rules! kilograms_per_second {
     ($num:expr) => {
         $crate::MassRate::<$crate::Kilograms, $crate::Seconds>::from(&$num)
     };
 }
+
+#[macro_export]
+macro_rules! kilograms_meters_per_second {
+    ($num:expr) => {
+        $crate::Momentum::<$crate::Kilograms, $crate::Meters, $crate::Seconds>::from(&$num)
+    };
+}
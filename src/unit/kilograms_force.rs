@@ -0,0 +1,30 @@
+use crate::{ForceUnit, Kilograms, Meters, Seconds, Unit, STANDARD_GRAVITY};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct KilogramsForce;
+impl Unit for KilogramsForce {
+    const UNIT_NAME: &'static str = "kilograms(force)";
+    const UNIT_SHORT_NAME: &'static str = "kgf";
+    const UNIT_SUFFIX: &'static str = "kgf";
+}
+impl ForceUnit for KilogramsForce {
+    const NEWTONS_IN_UNIT: f64 = STANDARD_GRAVITY;
+
+    type UnitMass = Kilograms;
+    type UnitLength = Meters;
+    type UnitTime = Seconds;
+}
+
+#[macro_export]
+macro_rules! kilograms_force {
+    ($num:expr) => {
+        $crate::Force::<$crate::KilogramsForce>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! kgf {
+    ($num:expr) => {
+        $crate::Force::<$crate::KilogramsForce>::from(&$num)
+    };
+}
@@ -0,0 +1,19 @@
+use crate::{MolarMassUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct KilogramsPerMole;
+impl Unit for KilogramsPerMole {
+    const UNIT_NAME: &'static str = "kilograms per mole";
+    const UNIT_SHORT_NAME: &'static str = "kg/mol";
+    const UNIT_SUFFIX: &'static str = "kg/mol";
+}
+impl MolarMassUnit for KilogramsPerMole {
+    const KILOGRAMS_PER_MOLE_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! kilograms_per_mole {
+    ($num:expr) => {
+        $crate::MolarMass::<$crate::KilogramsPerMole>::from(&$num)
+    };
+}
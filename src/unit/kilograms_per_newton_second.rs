@@ -0,0 +1,19 @@
+use crate::{TsfcUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct KilogramsPerNewtonSecond;
+impl Unit for KilogramsPerNewtonSecond {
+    const UNIT_NAME: &'static str = "kilograms per newton-second";
+    const UNIT_SHORT_NAME: &'static str = "kg/(N*s)";
+    const UNIT_SUFFIX: &'static str = "kg/(N*s)";
+}
+impl TsfcUnit for KilogramsPerNewtonSecond {
+    const KILOGRAMS_PER_NEWTON_SECOND_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! kilograms_per_newton_second {
+    ($num:expr) => {
+        $crate::Tsfc::<$crate::KilogramsPerNewtonSecond>::from(&$num)
+    };
+}
@@ -0,0 +1,19 @@
+use crate::{FrequencyUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Kilohertz;
+impl Unit for Kilohertz {
+    const UNIT_NAME: &'static str = "kilohertz";
+    const UNIT_SHORT_NAME: &'static str = "kHz";
+    const UNIT_SUFFIX: &'static str = "kHz";
+}
+impl FrequencyUnit for Kilohertz {
+    const HERTZ_IN_UNIT: f64 = 1_000.0;
+}
+
+#[macro_export]
+macro_rules! kilohertz {
+    ($num:expr) => {
+        $crate::Frequency::<$crate::Kilohertz>::from(&$num)
+    };
+}
@@ -1,15 +1,6 @@
-use crate::{LengthUnit, Unit};
+use crate::{Kilo, Meters, Prefixed};
 
-#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
-pub struct Kilometers;
-impl Unit for Kilometers {
-    const UNIT_NAME: &'static str = "kilometers";
-    const UNIT_SHORT_NAME: &'static str = "km";
-    const UNIT_SUFFIX: &'static str = "km";
-}
-impl LengthUnit for Kilometers {
-    const METERS_IN_UNIT: f64 = 1_000.0;
-}
+pub type Kilometers = Prefixed<Kilo, Meters>;
 
 #[macro_export]
 macro_rules! kilometers {
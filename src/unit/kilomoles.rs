@@ -0,0 +1,19 @@
+use crate::{AmountUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Kilomoles;
+impl Unit for Kilomoles {
+    const UNIT_NAME: &'static str = "kilomoles";
+    const UNIT_SHORT_NAME: &'static str = "kmol";
+    const UNIT_SUFFIX: &'static str = "kmol";
+}
+impl AmountUnit for Kilomoles {
+    const MOLES_IN_UNIT: f64 = 1_000.0;
+}
+
+#[macro_export]
+macro_rules! kilomoles {
+    ($num:expr) => {
+        $crate::Amount::<$crate::Kilomoles>::from(&$num)
+    };
+}
@@ -0,0 +1,19 @@
+use crate::{EnergyUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct KilowattHours;
+impl Unit for KilowattHours {
+    const UNIT_NAME: &'static str = "kilowatt hours";
+    const UNIT_SHORT_NAME: &'static str = "kWh";
+    const UNIT_SUFFIX: &'static str = "kWh";
+}
+impl EnergyUnit for KilowattHours {
+    const JOULES_IN_UNIT: f64 = 3_600_000.0;
+}
+
+#[macro_export]
+macro_rules! kilowatt_hours {
+    ($num:expr) => {
+        $crate::Energy::<$crate::KilowattHours>::from(&$num)
+    };
+}
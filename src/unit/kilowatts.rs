@@ -0,0 +1,19 @@
+use crate::{PowerUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Kilowatts;
+impl Unit for Kilowatts {
+    const UNIT_NAME: &'static str = "kilowatts";
+    const UNIT_SHORT_NAME: &'static str = "kW";
+    const UNIT_SUFFIX: &'static str = "kW";
+}
+impl PowerUnit for Kilowatts {
+    const WATTS_IN_UNIT: f64 = 1_000.0;
+}
+
+#[macro_export]
+macro_rules! kilowatts {
+    ($num:expr) => {
+        $crate::Power::<$crate::Kilowatts>::from(&$num)
+    };
+}
@@ -0,0 +1,41 @@
+use crate::{LengthUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct LightYears;
+impl Unit for LightYears {
+    const UNIT_NAME: &'static str = "light-years";
+    const UNIT_SHORT_NAME: &'static str = "ly";
+    const UNIT_SUFFIX: &'static str = "ly";
+}
+// Julian year (365.25 days) at the speed of light.
+impl LengthUnit for LightYears {
+    const METERS_IN_UNIT: f64 = 9_460_730_472_580_800.0;
+}
+
+#[macro_export]
+macro_rules! light_years {
+    ($num:expr) => {
+        $crate::Length::<$crate::LightYears>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! light_years2 {
+    ($num:expr) => {
+        $crate::Area::<$crate::LightYears>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! light_years3 {
+    ($num:expr) => {
+        $crate::Volume::<$crate::LightYears>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! light_years_per_second {
+    ($num:expr) => {
+        $crate::Velocity::<$crate::LightYears, $crate::Seconds>::from(&$num)
+    };
+}
@@ -0,0 +1,29 @@
+use crate::{Meters, Unit, VolumeUnit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Liters;
+impl Unit for Liters {
+    const UNIT_NAME: &'static str = "liters";
+    const UNIT_SHORT_NAME: &'static str = "L";
+    const UNIT_SUFFIX: &'static str = "L";
+}
+impl VolumeUnit for Liters {
+    const CUBIC_METERS_IN_UNIT: f64 = 0.001;
+    const VOLUME_SUFFIX: &'static str = "";
+
+    type UnitLength = Meters;
+}
+
+#[macro_export]
+macro_rules! liters {
+    ($num:expr) => {
+        $crate::Volume::<$crate::Liters>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! liters_per_minute {
+    ($num:expr) => {
+        $crate::VolumeRate::<$crate::Liters, $crate::Minutes>::from(&$num)
+    };
+}
@@ -25,6 +25,20 @@ macro_rules! meters2 {
     };
 }
 
+#[macro_export]
+macro_rules! meters3 {
+    ($num:expr) => {
+        $crate::Volume::<$crate::Meters>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! meters3_per_second {
+    ($num:expr) => {
+        $crate::VolumeRate::<$crate::Meters, $crate::Seconds>::from(&$num)
+    };
+}
+
 #[macro_export]
 macro_rules! meters_per_second {
     ($num:expr) => {
@@ -38,3 +52,10 @@ macro_rules! meters_per_second2 {
         $crate::Acceleration::<$crate::Meters, $crate::Seconds>::from(&$num)
     };
 }
+
+#[macro_export]
+macro_rules! meters_per_second3 {
+    ($num:expr) => {
+        $crate::Jerk::<$crate::Meters, $crate::Seconds>::from(&$num)
+    };
+}
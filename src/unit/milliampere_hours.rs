@@ -0,0 +1,19 @@
+use crate::{ChargeUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct MilliampereHours;
+impl Unit for MilliampereHours {
+    const UNIT_NAME: &'static str = "milliampere-hours";
+    const UNIT_SHORT_NAME: &'static str = "mAh";
+    const UNIT_SUFFIX: &'static str = "mAh";
+}
+impl ChargeUnit for MilliampereHours {
+    const COULOMBS_IN_UNIT: f64 = 3.6;
+}
+
+#[macro_export]
+macro_rules! milliampere_hours {
+    ($num:expr) => {
+        $crate::Charge::<$crate::MilliampereHours>::from(&$num)
+    };
+}
@@ -0,0 +1,20 @@
+use crate::{PressureUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct MillimetersOfMercury;
+impl Unit for MillimetersOfMercury {
+    const UNIT_NAME: &'static str = "millimeters of mercury";
+    const UNIT_SHORT_NAME: &'static str = "mmHg";
+    const UNIT_SUFFIX: &'static str = "mmHg";
+}
+// Conventional: 1mm of mercury at 13.5951 g/cm^3 under standard gravity.
+impl PressureUnit for MillimetersOfMercury {
+    const PASCALS_IN_UNIT: f64 = 133.322_387_415;
+}
+
+#[macro_export]
+macro_rules! millimeters_of_mercury {
+    ($num:expr) => {
+        $crate::Pressure::<$crate::MillimetersOfMercury>::from(&$num)
+    };
+}
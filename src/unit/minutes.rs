@@ -0,0 +1,19 @@
+use crate::{TimeUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Minutes;
+impl Unit for Minutes {
+    const UNIT_NAME: &'static str = "minutes";
+    const UNIT_SHORT_NAME: &'static str = "min";
+    const UNIT_SUFFIX: &'static str = "min";
+}
+impl TimeUnit for Minutes {
+    const SECONDS_IN_UNIT: f64 = 60.;
+}
+
+#[macro_export]
+macro_rules! minutes {
+    ($num:expr) => {
+        $crate::Time::<$crate::Minutes>::from(&$num)
+    };
+}
@@ -0,0 +1,34 @@
+use crate::{AngleUnit, Unit};
+use std::f64::consts::PI;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct MinutesOfRightAscension;
+impl Unit for MinutesOfRightAscension {
+    const UNIT_NAME: &'static str = "minutes of right ascension";
+    const UNIT_SHORT_NAME: &'static str = "mRA";
+    const UNIT_SUFFIX: &'static str = "ᵐ";
+}
+impl AngleUnit for MinutesOfRightAscension {
+    const RADIANS_IN_UNIT: f64 = PI / 12f64 / 60f64;
+}
+
+#[macro_export]
+macro_rules! ra_minutes {
+    ($num:expr) => {
+        $crate::Angle::<$crate::MinutesOfRightAscension>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! ra_minutes_per_second {
+    ($num:expr) => {
+        $crate::AngularVelocity::<$crate::MinutesOfRightAscension, $crate::Seconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! ra_minutes_per_second2 {
+    ($num:expr) => {
+        $crate::AngularAcceleration::<$crate::MinutesOfRightAscension, $crate::Seconds>::from(&$num)
+    };
+}
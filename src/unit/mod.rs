@@ -8,19 +8,41 @@ pub trait Unit {
 // Unitless
 pub(crate) mod scalar;
 
+// Custom units
+pub(crate) mod define;
+
+// SI prefixes
+pub(crate) mod prefixed;
+
 // Angular
 pub(crate) mod arcminutes;
 pub(crate) mod arcseconds;
 pub(crate) mod degrees;
+pub(crate) mod gradians;
+pub(crate) mod hours_of_right_ascension;
+pub(crate) mod minutes_of_right_ascension;
+pub(crate) mod nato_mils;
 pub(crate) mod radians;
+pub(crate) mod revolutions;
+pub(crate) mod seconds_of_right_ascension;
 
 // Distance
 pub(crate) mod astronomical_units;
+pub(crate) mod fathoms;
 pub(crate) mod feet;
+pub(crate) mod inches;
 pub(crate) mod kilometers;
+pub(crate) mod light_years;
 pub(crate) mod meters;
 pub(crate) mod miles;
 pub(crate) mod nautical_miles;
+pub(crate) mod parsecs;
+pub(crate) mod us_survey_feet;
+pub(crate) mod yards;
+
+// Volume
+pub(crate) mod liters;
+pub(crate) mod us_gallons;
 
 // Temperature
 pub(crate) mod celsius;
@@ -29,18 +51,100 @@ pub(crate) mod kelvin;
 pub(crate) mod rankine;
 
 // Mass
+pub(crate) mod earth_masses;
+pub(crate) mod grains;
+pub(crate) mod grams;
 pub(crate) mod kilograms;
+pub(crate) mod ounces;
 pub(crate) mod pounds_mass;
+pub(crate) mod short_tons;
 pub(crate) mod slugs;
+pub(crate) mod solar_masses;
+pub(crate) mod tonnes;
 
 // Time
+pub(crate) mod days;
 pub(crate) mod hours;
+pub(crate) mod julian_years;
+pub(crate) mod minutes;
 pub(crate) mod seconds;
+pub(crate) mod weeks;
 
 // Force
+pub(crate) mod dynes;
+pub(crate) mod kilograms_force;
 pub(crate) mod newtons;
+pub(crate) mod poundals;
 pub(crate) mod pounds_force;
 
 // Pressure
+pub(crate) mod atmospheres;
+pub(crate) mod bar;
+pub(crate) mod inches_of_mercury;
+pub(crate) mod millimeters_of_mercury;
 pub(crate) mod pascals;
 pub(crate) mod pounds_square_foot;
+pub(crate) mod pounds_square_inch;
+pub(crate) mod torr;
+
+// Energy
+pub(crate) mod british_thermal_units;
+pub(crate) mod foot_pounds;
+pub(crate) mod joules;
+pub(crate) mod kilocalories;
+pub(crate) mod kilowatt_hours;
+
+// Power
+pub(crate) mod foot_pounds_per_second;
+pub(crate) mod horsepower;
+pub(crate) mod kilowatts;
+pub(crate) mod watts;
+
+// Frequency
+pub(crate) mod cycles_per_minute;
+pub(crate) mod hertz;
+pub(crate) mod kilohertz;
+pub(crate) mod rpm;
+
+// Electrical
+pub(crate) mod amperes;
+pub(crate) mod coulombs;
+pub(crate) mod farads;
+pub(crate) mod henries;
+pub(crate) mod milliampere_hours;
+pub(crate) mod ohms;
+pub(crate) mod volts;
+
+// Viscosity
+pub(crate) mod centipoise;
+pub(crate) mod centistokes;
+pub(crate) mod pascal_seconds;
+pub(crate) mod poise;
+pub(crate) mod square_meters_per_second;
+
+// Amount of substance
+pub(crate) mod kilomoles;
+pub(crate) mod moles;
+
+// Molar mass
+pub(crate) mod grams_per_mole;
+pub(crate) mod kilograms_per_mole;
+
+// Gas constant
+pub(crate) mod foot_pounds_per_slug_rankine;
+pub(crate) mod joules_per_kilogram_kelvin;
+
+// Stiffness
+pub(crate) mod newton_meters_per_radian;
+pub(crate) mod newtons_per_meter;
+pub(crate) mod pounds_force_per_inch;
+
+// Damping
+pub(crate) mod newton_meter_seconds_per_radian;
+pub(crate) mod newton_seconds_per_meter;
+pub(crate) mod pounds_force_seconds_per_inch;
+
+// Fuel consumption
+pub(crate) mod grams_per_kilonewton_second;
+pub(crate) mod kilograms_per_newton_second;
+pub(crate) mod pounds_per_pound_force_hour;
@@ -0,0 +1,19 @@
+use crate::{AmountUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Moles;
+impl Unit for Moles {
+    const UNIT_NAME: &'static str = "moles";
+    const UNIT_SHORT_NAME: &'static str = "mol";
+    const UNIT_SUFFIX: &'static str = "mol";
+}
+impl AmountUnit for Moles {
+    const MOLES_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! moles {
+    ($num:expr) => {
+        $crate::Amount::<$crate::Moles>::from(&$num)
+    };
+}
@@ -0,0 +1,34 @@
+use crate::{AngleUnit, Unit};
+use std::f64::consts::PI;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct NatoMils;
+impl Unit for NatoMils {
+    const UNIT_NAME: &'static str = "NATO mils";
+    const UNIT_SHORT_NAME: &'static str = "mil";
+    const UNIT_SUFFIX: &'static str = "mil";
+}
+impl AngleUnit for NatoMils {
+    const RADIANS_IN_UNIT: f64 = PI / 3_200f64;
+}
+
+#[macro_export]
+macro_rules! mils {
+    ($num:expr) => {
+        $crate::Angle::<$crate::NatoMils>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! mils_per_second {
+    ($num:expr) => {
+        $crate::AngularVelocity::<$crate::NatoMils, $crate::Seconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! mils_per_second2 {
+    ($num:expr) => {
+        $crate::AngularAcceleration::<$crate::NatoMils, $crate::Seconds>::from(&$num)
+    };
+}
@@ -0,0 +1,19 @@
+use crate::{AngularDampingCoefficientUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct NewtonMeterSecondsPerRadian;
+impl Unit for NewtonMeterSecondsPerRadian {
+    const UNIT_NAME: &'static str = "newton-meter-seconds per radian";
+    const UNIT_SHORT_NAME: &'static str = "N*m*s/rad";
+    const UNIT_SUFFIX: &'static str = "N*m*s/rad";
+}
+impl AngularDampingCoefficientUnit for NewtonMeterSecondsPerRadian {
+    const NEWTON_METER_SECONDS_PER_RADIAN_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! newton_meter_seconds_per_radian {
+    ($num:expr) => {
+        $crate::AngularDampingCoefficient::<$crate::NewtonMeterSecondsPerRadian>::from(&$num)
+    };
+}
@@ -0,0 +1,19 @@
+use crate::{TorsionalStiffnessUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct NewtonMetersPerRadian;
+impl Unit for NewtonMetersPerRadian {
+    const UNIT_NAME: &'static str = "newton-meters per radian";
+    const UNIT_SHORT_NAME: &'static str = "N*m/rad";
+    const UNIT_SUFFIX: &'static str = "N*m/rad";
+}
+impl TorsionalStiffnessUnit for NewtonMetersPerRadian {
+    const NEWTON_METERS_PER_RADIAN_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! newton_meters_per_radian {
+    ($num:expr) => {
+        $crate::TorsionalStiffness::<$crate::NewtonMetersPerRadian>::from(&$num)
+    };
+}
@@ -0,0 +1,19 @@
+use crate::{DampingCoefficientUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct NewtonSecondsPerMeter;
+impl Unit for NewtonSecondsPerMeter {
+    const UNIT_NAME: &'static str = "newton-seconds per meter";
+    const UNIT_SHORT_NAME: &'static str = "N*s/m";
+    const UNIT_SUFFIX: &'static str = "N*s/m";
+}
+impl DampingCoefficientUnit for NewtonSecondsPerMeter {
+    const NEWTON_SECONDS_PER_METER_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! newton_seconds_per_meter {
+    ($num:expr) => {
+        $crate::DampingCoefficient::<$crate::NewtonSecondsPerMeter>::from(&$num)
+    };
+}
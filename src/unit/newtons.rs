@@ -28,3 +28,10 @@ macro_rules! newton_meters {
         $crate::Torque::<$crate::Newtons, $crate::Meters>::from(&$num)
     };
 }
+
+#[macro_export]
+macro_rules! newton_seconds {
+    ($num:expr) => {
+        $crate::Impulse::<$crate::Kilograms, $crate::Meters, $crate::Seconds>::from(&$num)
+    };
+}
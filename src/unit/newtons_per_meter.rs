@@ -0,0 +1,19 @@
+use crate::{StiffnessUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct NewtonsPerMeter;
+impl Unit for NewtonsPerMeter {
+    const UNIT_NAME: &'static str = "newtons per meter";
+    const UNIT_SHORT_NAME: &'static str = "N/m";
+    const UNIT_SUFFIX: &'static str = "N/m";
+}
+impl StiffnessUnit for NewtonsPerMeter {
+    const NEWTONS_PER_METER_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! newtons_per_meter {
+    ($num:expr) => {
+        $crate::Stiffness::<$crate::NewtonsPerMeter>::from(&$num)
+    };
+}
@@ -0,0 +1,19 @@
+use crate::{ResistanceUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Ohms;
+impl Unit for Ohms {
+    const UNIT_NAME: &'static str = "ohms";
+    const UNIT_SHORT_NAME: &'static str = "Ω";
+    const UNIT_SUFFIX: &'static str = "Ω";
+}
+impl ResistanceUnit for Ohms {
+    const OHMS_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! ohms {
+    ($num:expr) => {
+        $crate::Resistance::<$crate::Ohms>::from(&$num)
+    };
+}
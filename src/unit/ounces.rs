@@ -0,0 +1,40 @@
+use crate::{MassUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Ounces;
+impl Unit for Ounces {
+    const UNIT_NAME: &'static str = "ounces";
+    const UNIT_SHORT_NAME: &'static str = "oz";
+    const UNIT_SUFFIX: &'static str = "oz";
+}
+impl MassUnit for Ounces {
+    const GRAMS_IN_UNIT: f64 = 28.349_523_125;
+}
+
+#[macro_export]
+macro_rules! ounces {
+    ($num:expr) => {
+        $crate::Mass::<$crate::Ounces>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! ounces_per_inch3 {
+    ($num:expr) => {
+        $crate::Density::<$crate::Ounces, $crate::Inches>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! ounces_per_second {
+    ($num:expr) => {
+        $crate::MassRate::<$crate::Ounces, $crate::Seconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! ounces_per_hour {
+    ($num:expr) => {
+        $crate::MassRate::<$crate::Ounces, $crate::Hours>::from(&$num)
+    };
+}
@@ -0,0 +1,41 @@
+use crate::{LengthUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Parsecs;
+impl Unit for Parsecs {
+    const UNIT_NAME: &'static str = "parsecs";
+    const UNIT_SHORT_NAME: &'static str = "pc";
+    const UNIT_SUFFIX: &'static str = "pc";
+}
+// The distance at which one astronomical unit subtends one arcsecond: 648,000 / pi AU.
+impl LengthUnit for Parsecs {
+    const METERS_IN_UNIT: f64 = 149_597_870_700.0 * 648_000.0 / std::f64::consts::PI;
+}
+
+#[macro_export]
+macro_rules! parsecs {
+    ($num:expr) => {
+        $crate::Length::<$crate::Parsecs>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! parsecs2 {
+    ($num:expr) => {
+        $crate::Area::<$crate::Parsecs>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! parsecs3 {
+    ($num:expr) => {
+        $crate::Volume::<$crate::Parsecs>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! parsecs_per_second {
+    ($num:expr) => {
+        $crate::Velocity::<$crate::Parsecs, $crate::Seconds>::from(&$num)
+    };
+}
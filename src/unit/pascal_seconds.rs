@@ -0,0 +1,19 @@
+use crate::{DynamicViscosityUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct PascalSeconds;
+impl Unit for PascalSeconds {
+    const UNIT_NAME: &'static str = "pascal-seconds";
+    const UNIT_SHORT_NAME: &'static str = "Pa·s";
+    const UNIT_SUFFIX: &'static str = "Pa·s";
+}
+impl DynamicViscosityUnit for PascalSeconds {
+    const PASCAL_SECONDS_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! pascal_seconds {
+    ($num:expr) => {
+        $crate::DynamicViscosity::<$crate::PascalSeconds>::from(&$num)
+    };
+}
@@ -0,0 +1,19 @@
+use crate::{DynamicViscosityUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Poise;
+impl Unit for Poise {
+    const UNIT_NAME: &'static str = "poise";
+    const UNIT_SHORT_NAME: &'static str = "P";
+    const UNIT_SUFFIX: &'static str = "P";
+}
+impl DynamicViscosityUnit for Poise {
+    const PASCAL_SECONDS_IN_UNIT: f64 = 0.1;
+}
+
+#[macro_export]
+macro_rules! poise {
+    ($num:expr) => {
+        $crate::DynamicViscosity::<$crate::Poise>::from(&$num)
+    };
+}
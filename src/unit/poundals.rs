@@ -0,0 +1,31 @@
+use crate::{Feet, ForceUnit, PoundsMass, Seconds, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Poundals;
+impl Unit for Poundals {
+    const UNIT_NAME: &'static str = "poundals";
+    const UNIT_SHORT_NAME: &'static str = "pdl";
+    const UNIT_SUFFIX: &'static str = "pdl";
+}
+// The force that accelerates one pound of mass at one foot per second squared.
+impl ForceUnit for Poundals {
+    const NEWTONS_IN_UNIT: f64 = 0.453_592_37 * 0.304_8;
+
+    type UnitMass = PoundsMass;
+    type UnitLength = Feet;
+    type UnitTime = Seconds;
+}
+
+#[macro_export]
+macro_rules! poundals {
+    ($num:expr) => {
+        $crate::Force::<$crate::Poundals>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! pdl {
+    ($num:expr) => {
+        $crate::Force::<$crate::Poundals>::from(&$num)
+    };
+}
@@ -7,8 +7,9 @@ impl Unit for PoundsForce {
     const UNIT_SHORT_NAME: &'static str = "lbf";
     const UNIT_SUFFIX: &'static str = "lbf";
 }
+// One pound of mass under standard gravity.
 impl ForceUnit for PoundsForce {
-    const NEWTONS_IN_UNIT: f64 = 1. / 0.224_809;
+    const NEWTONS_IN_UNIT: f64 = 4.448_221_615_260_5;
 
     type UnitMass = PoundsMass;
     type UnitLength = Feet;
@@ -21,10 +22,3 @@ macro_rules! pounds_force {
         $crate::Force::<$crate::PoundsForce>::from(&$num)
     };
 }
-
-#[macro_export]
-macro_rules! pdl {
-    ($num:expr) => {
-        $crate::Force::<$crate::PoundsForce>::from(&$num)
-    };
-}
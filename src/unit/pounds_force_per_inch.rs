@@ -0,0 +1,19 @@
+use crate::{StiffnessUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct PoundsForcePerInch;
+impl Unit for PoundsForcePerInch {
+    const UNIT_NAME: &'static str = "pounds(force) per inch";
+    const UNIT_SHORT_NAME: &'static str = "lbf/in";
+    const UNIT_SUFFIX: &'static str = "lbf/in";
+}
+impl StiffnessUnit for PoundsForcePerInch {
+    const NEWTONS_PER_METER_IN_UNIT: f64 = 4.448_221_615_260_5 / 0.0254;
+}
+
+#[macro_export]
+macro_rules! pounds_force_per_inch {
+    ($num:expr) => {
+        $crate::Stiffness::<$crate::PoundsForcePerInch>::from(&$num)
+    };
+}
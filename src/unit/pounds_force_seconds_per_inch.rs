@@ -0,0 +1,19 @@
+use crate::{DampingCoefficientUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct PoundsForceSecondsPerInch;
+impl Unit for PoundsForceSecondsPerInch {
+    const UNIT_NAME: &'static str = "pound(force)-seconds per inch";
+    const UNIT_SHORT_NAME: &'static str = "lbf*s/in";
+    const UNIT_SUFFIX: &'static str = "lbf*s/in";
+}
+impl DampingCoefficientUnit for PoundsForceSecondsPerInch {
+    const NEWTON_SECONDS_PER_METER_IN_UNIT: f64 = 4.448_221_615_260_5 / 0.0254;
+}
+
+#[macro_export]
+macro_rules! pounds_force_seconds_per_inch {
+    ($num:expr) => {
+        $crate::DampingCoefficient::<$crate::PoundsForceSecondsPerInch>::from(&$num)
+    };
+}
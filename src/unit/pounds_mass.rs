@@ -31,3 +31,17 @@ macro_rules! pounds_per_feet3 {
         $crate::Density::<$crate::PoundsMass, $crate::Feet>::from(&$num)
     };
 }
+
+#[macro_export]
+macro_rules! pounds_per_feet2 {
+    ($num:expr) => {
+        $crate::ArealDensity::<$crate::PoundsMass, $crate::Feet>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! pounds_per_feet {
+    ($num:expr) => {
+        $crate::LinearDensity::<$crate::PoundsMass, $crate::Feet>::from(&$num)
+    };
+}
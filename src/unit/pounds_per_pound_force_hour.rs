@@ -0,0 +1,20 @@
+use crate::{TsfcUnit, Unit, STANDARD_GRAVITY};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct PoundsPerPoundForceHour;
+impl Unit for PoundsPerPoundForceHour {
+    const UNIT_NAME: &'static str = "pounds per pound(force)-hour";
+    const UNIT_SHORT_NAME: &'static str = "lb/(lbf*h)";
+    const UNIT_SUFFIX: &'static str = "lb/(lbf*h)";
+}
+// The pound mass and pound force cancel through g0.
+impl TsfcUnit for PoundsPerPoundForceHour {
+    const KILOGRAMS_PER_NEWTON_SECOND_IN_UNIT: f64 = 1. / (STANDARD_GRAVITY * 3_600.);
+}
+
+#[macro_export]
+macro_rules! pounds_per_pound_force_hour {
+    ($num:expr) => {
+        $crate::Tsfc::<$crate::PoundsPerPoundForceHour>::from(&$num)
+    };
+}
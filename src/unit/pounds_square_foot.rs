@@ -8,7 +8,7 @@ impl Unit for PoundsSquareFoot {
     const UNIT_SUFFIX: &'static str = "lb/ft^2";
 }
 impl PressureUnit for PoundsSquareFoot {
-    const PASCALS_IN_UNIT: f64 = 47.880;
+    const PASCALS_IN_UNIT: f64 = 4.448_221_615_260_5 / (0.304_8 * 0.304_8);
 }
 
 #[macro_export]
@@ -0,0 +1,26 @@
+use crate::{PressureUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct PoundsSquareInch;
+impl Unit for PoundsSquareInch {
+    const UNIT_NAME: &'static str = "pounds per square inch";
+    const UNIT_SHORT_NAME: &'static str = "psi";
+    const UNIT_SUFFIX: &'static str = "psi";
+}
+impl PressureUnit for PoundsSquareInch {
+    const PASCALS_IN_UNIT: f64 = 4.448_221_615_260_5 / (0.025_4 * 0.025_4);
+}
+
+#[macro_export]
+macro_rules! pounds_square_inch {
+    ($num:expr) => {
+        $crate::Pressure::<$crate::PoundsSquareInch>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! psi {
+    ($num:expr) => {
+        $crate::Pressure::<$crate::PoundsSquareInch>::from(&$num)
+    };
+}
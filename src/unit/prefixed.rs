@@ -0,0 +1,414 @@
+use crate::{
+    AngleUnit, Bar, ForceUnit, Grams, LengthUnit, MassUnit, Meters, Newtons, Pascals, PressureUnit,
+    Radians, Seconds, TimeUnit, Unit,
+};
+use std::{fmt::Debug, marker::PhantomData};
+
+/// A decimal SI prefix that can be applied to any unit with `Prefixed`.
+pub trait SiPrefix: Copy + Debug + Default + Eq + Ord + PartialEq + PartialOrd + 'static {
+    const PREFIX_NAME: &'static str;
+    const PREFIX_SYMBOL: &'static str;
+    const FACTOR: f64;
+}
+
+macro_rules! si_prefix {
+    ($ty:ident, $name:literal, $symbol:literal, $factor:expr) => {
+        #[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+        pub struct $ty;
+        impl SiPrefix for $ty {
+            const PREFIX_NAME: &'static str = $name;
+            const PREFIX_SYMBOL: &'static str = $symbol;
+            const FACTOR: f64 = $factor;
+        }
+    };
+}
+si_prefix!(Nano, "nano", "n", 1e-9);
+si_prefix!(Micro, "micro", "µ", 1e-6);
+si_prefix!(Milli, "milli", "m", 1e-3);
+si_prefix!(Centi, "centi", "c", 1e-2);
+si_prefix!(Deci, "deci", "d", 1e-1);
+si_prefix!(Hecto, "hecto", "h", 1e2);
+si_prefix!(Kilo, "kilo", "k", 1e3);
+si_prefix!(Mega, "mega", "M", 1e6);
+si_prefix!(Giga, "giga", "G", 1e9);
+
+/// The unit U scaled by the SI prefix P, e.g. `Prefixed<Kilo, Newtons>` is kN.
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Prefixed<P: SiPrefix, U: Unit>(PhantomData<(P, U)>);
+
+// Unit names are &'static str, so the prefixed names are assembled at compile time
+// into a fixed-size buffer, which is then viewed as a str.
+const NAME_CAPACITY: usize = 64;
+type NameBuffer = ([u8; NAME_CAPACITY], usize);
+
+const fn concat_names(prefix: &str, name: &str) -> NameBuffer {
+    let (prefix, name) = (prefix.as_bytes(), name.as_bytes());
+    assert!(
+        prefix.len() + name.len() <= NAME_CAPACITY,
+        "unit name is too long to prefix"
+    );
+    let mut buf = [0u8; NAME_CAPACITY];
+    let mut i = 0;
+    while i < prefix.len() {
+        buf[i] = prefix[i];
+        i += 1;
+    }
+    let mut j = 0;
+    while j < name.len() {
+        buf[i + j] = name[j];
+        j += 1;
+    }
+    (buf, prefix.len() + name.len())
+}
+
+const fn name_str(name: &'static NameBuffer) -> &'static str {
+    match std::str::from_utf8(name.0.split_at(name.1).0) {
+        Ok(s) => s,
+        Err(_) => panic!("prefixed unit name is not utf8"),
+    }
+}
+
+impl<P, U> Prefixed<P, U>
+where
+    P: SiPrefix,
+    U: Unit,
+{
+    const NAME: NameBuffer = concat_names(P::PREFIX_NAME, U::UNIT_NAME);
+    const SHORT_NAME: NameBuffer = concat_names(P::PREFIX_SYMBOL, U::UNIT_SHORT_NAME);
+    const SUFFIX: NameBuffer = concat_names(P::PREFIX_SYMBOL, U::UNIT_SUFFIX);
+}
+
+impl<P, U> Unit for Prefixed<P, U>
+where
+    P: SiPrefix,
+    U: Unit,
+{
+    const UNIT_NAME: &'static str = name_str(&Self::NAME);
+    const UNIT_SHORT_NAME: &'static str = name_str(&Self::SHORT_NAME);
+    const UNIT_SUFFIX: &'static str = name_str(&Self::SUFFIX);
+}
+
+impl<P, U> AngleUnit for Prefixed<P, U>
+where
+    P: SiPrefix,
+    U: AngleUnit,
+{
+    const RADIANS_IN_UNIT: f64 = P::FACTOR * U::RADIANS_IN_UNIT;
+}
+
+impl<P, U> LengthUnit for Prefixed<P, U>
+where
+    P: SiPrefix,
+    U: LengthUnit,
+{
+    const METERS_IN_UNIT: f64 = P::FACTOR * U::METERS_IN_UNIT;
+}
+
+impl<P, U> MassUnit for Prefixed<P, U>
+where
+    P: SiPrefix,
+    U: MassUnit,
+{
+    const GRAMS_IN_UNIT: f64 = P::FACTOR * U::GRAMS_IN_UNIT;
+}
+
+impl<P, U> TimeUnit for Prefixed<P, U>
+where
+    P: SiPrefix,
+    U: TimeUnit,
+{
+    const SECONDS_IN_UNIT: f64 = P::FACTOR * U::SECONDS_IN_UNIT;
+}
+
+impl<P, U> ForceUnit for Prefixed<P, U>
+where
+    P: SiPrefix,
+    U: ForceUnit,
+{
+    const NEWTONS_IN_UNIT: f64 = P::FACTOR * U::NEWTONS_IN_UNIT;
+
+    type UnitMass = U::UnitMass;
+    type UnitLength = U::UnitLength;
+    type UnitTime = U::UnitTime;
+}
+
+impl<P, U> PressureUnit for Prefixed<P, U>
+where
+    P: SiPrefix,
+    U: PressureUnit,
+{
+    const PASCALS_IN_UNIT: f64 = P::FACTOR * U::PASCALS_IN_UNIT;
+}
+
+// Angle
+pub type Milliradians = Prefixed<Milli, Radians>;
+
+#[macro_export]
+macro_rules! milliradians {
+    ($num:expr) => {
+        $crate::Angle::<$crate::Milliradians>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! milliradians_per_second {
+    ($num:expr) => {
+        $crate::AngularVelocity::<$crate::Milliradians, $crate::Seconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! milliradians_per_second2 {
+    ($num:expr) => {
+        $crate::AngularAcceleration::<$crate::Milliradians, $crate::Seconds>::from(&$num)
+    };
+}
+
+// Length
+pub type Centimeters = Prefixed<Centi, Meters>;
+pub type Millimeters = Prefixed<Milli, Meters>;
+pub type Micrometers = Prefixed<Micro, Meters>;
+
+#[macro_export]
+macro_rules! centimeters {
+    ($num:expr) => {
+        $crate::Length::<$crate::Centimeters>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! centimeters2 {
+    ($num:expr) => {
+        $crate::Area::<$crate::Centimeters>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! centimeters3 {
+    ($num:expr) => {
+        $crate::Volume::<$crate::Centimeters>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! centimeters_per_second {
+    ($num:expr) => {
+        $crate::Velocity::<$crate::Centimeters, $crate::Seconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! millimeters {
+    ($num:expr) => {
+        $crate::Length::<$crate::Millimeters>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! millimeters2 {
+    ($num:expr) => {
+        $crate::Area::<$crate::Millimeters>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! millimeters3 {
+    ($num:expr) => {
+        $crate::Volume::<$crate::Millimeters>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! millimeters_per_second {
+    ($num:expr) => {
+        $crate::Velocity::<$crate::Millimeters, $crate::Seconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! micrometers {
+    ($num:expr) => {
+        $crate::Length::<$crate::Micrometers>::from(&$num)
+    };
+}
+
+// Mass
+pub type Milligrams = Prefixed<Milli, Grams>;
+#[macro_export]
+macro_rules! milligrams {
+    ($num:expr) => {
+        $crate::Mass::<$crate::Milligrams>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! milligrams_per_meter3 {
+    ($num:expr) => {
+        $crate::Density::<$crate::Milligrams, $crate::Meters>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! milligrams_per_second {
+    ($num:expr) => {
+        $crate::MassRate::<$crate::Milligrams, $crate::Seconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! milligrams_per_hour {
+    ($num:expr) => {
+        $crate::MassRate::<$crate::Milligrams, $crate::Hours>::from(&$num)
+    };
+}
+
+// Time
+pub type Milliseconds = Prefixed<Milli, Seconds>;
+pub type Microseconds = Prefixed<Micro, Seconds>;
+pub type Nanoseconds = Prefixed<Nano, Seconds>;
+
+#[macro_export]
+macro_rules! milliseconds {
+    ($num:expr) => {
+        $crate::Time::<$crate::Milliseconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! microseconds {
+    ($num:expr) => {
+        $crate::Time::<$crate::Microseconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! nanoseconds {
+    ($num:expr) => {
+        $crate::Time::<$crate::Nanoseconds>::from(&$num)
+    };
+}
+
+// Force
+pub type Kilonewtons = Prefixed<Kilo, Newtons>;
+
+#[macro_export]
+macro_rules! kilonewtons {
+    ($num:expr) => {
+        $crate::Force::<$crate::Kilonewtons>::from(&$num)
+    };
+}
+
+// Pressure
+pub type Hectopascals = Prefixed<Hecto, Pascals>;
+pub type Kilopascals = Prefixed<Kilo, Pascals>;
+pub type Megapascals = Prefixed<Mega, Pascals>;
+pub type Millibar = Prefixed<Milli, Bar>;
+
+#[macro_export]
+macro_rules! hectopascals {
+    ($num:expr) => {
+        $crate::Pressure::<$crate::Hectopascals>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! kilopascals {
+    ($num:expr) => {
+        $crate::Pressure::<$crate::Kilopascals>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! megapascals {
+    ($num:expr) => {
+        $crate::Pressure::<$crate::Megapascals>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! millibar {
+    ($num:expr) => {
+        $crate::Pressure::<$crate::Millibar>::from(&$num)
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        grams, kilograms, kilograms_per_meter3, meters, newtons, pascals, seconds, Density, Force,
+        Giga, Grams, Hectopascals, Kilo, Kilograms, Kilometers, Kilonewtons, Length, Mass,
+        Megapascals, Meters, Micrometers, Milli, Millimeters, Nano, Newtons, Pascals, Prefixed,
+        Pressure, Seconds, Time, Unit,
+    };
+    use approx::assert_abs_diff_eq;
+    use std::any::TypeId;
+
+    #[test]
+    fn test_prefixed_names() {
+        assert_eq!(Millimeters::UNIT_NAME, "millimeters");
+        assert_eq!(Millimeters::UNIT_SHORT_NAME, "mm");
+        assert_eq!(Micrometers::UNIT_SHORT_NAME, "µm");
+        assert_eq!(Kilonewtons::UNIT_SHORT_NAME, "kN");
+        assert_eq!(Megapascals::UNIT_SUFFIX, "MPa");
+        assert_eq!(format!("{}", megapascals!(2)), "2MPa");
+    }
+
+    #[test]
+    fn test_prefixed_conversions() {
+        assert_abs_diff_eq!(meters!(millimeters!(1_500)), meters!(1.5));
+        assert_abs_diff_eq!(
+            micrometers!(millimeters!(2)),
+            micrometers!(2_000),
+            epsilon = 1e-9
+        );
+        assert_abs_diff_eq!(kilograms!(grams!(2_500)), kilograms!(2.5));
+        assert_abs_diff_eq!(
+            Mass::<Prefixed<Milli, Grams>>::from(&grams!(1)),
+            Mass::<Prefixed<Milli, Grams>>::from(1_000)
+        );
+        assert_abs_diff_eq!(seconds!(milliseconds!(250)), seconds!(0.25));
+        assert_abs_diff_eq!(newtons!(kilonewtons!(3)), newtons!(3_000));
+        assert_abs_diff_eq!(pascals!(megapascals!(1)), pascals!(1_000_000));
+        assert_abs_diff_eq!(kilopascals!(megapascals!(1)), kilopascals!(1_000));
+    }
+
+    #[test]
+    fn test_prefixed_arbitrary() {
+        let t = Time::<Prefixed<Nano, Seconds>>::from(5);
+        assert_abs_diff_eq!(seconds!(t), seconds!(5e-9), epsilon = 1e-18);
+        let d = Length::<Prefixed<Giga, Meters>>::from(&meters!(3e9));
+        assert_abs_diff_eq!(d.f64(), 3.);
+    }
+
+    #[test]
+    fn test_prefixed_dyn() {
+        let f: Force<Newtons> = kilonewtons!(1).as_dyn().into();
+        assert_abs_diff_eq!(f, newtons!(1_000));
+        let f: Force<Kilonewtons> = newtons!(2_500).as_dyn().into();
+        assert_abs_diff_eq!(f, kilonewtons!(2.5));
+        let p: Pressure<Pascals> = kilopascals!(1).as_dyn().into();
+        assert_abs_diff_eq!(p, pascals!(1_000));
+        let p: Pressure<Pascals> = megapascals!(1).as_dyn().into();
+        assert_abs_diff_eq!(p, pascals!(1_000_000));
+        let p: Pressure<Pascals> = hectopascals!(1_013.25).as_dyn().into();
+        assert_abs_diff_eq!(p, pascals!(101_325), epsilon = 1e-9);
+        let p: Pressure<Hectopascals> = millibar!(1_013.25).as_dyn().into();
+        assert_abs_diff_eq!(p, hectopascals!(1_013.25), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_prefixed_base_units_are_not_duplicated() {
+        assert_eq!(
+            TypeId::of::<Kilograms>(),
+            TypeId::of::<Prefixed<Kilo, Grams>>()
+        );
+        assert_eq!(
+            TypeId::of::<Kilometers>(),
+            TypeId::of::<Prefixed<Kilo, Meters>>()
+        );
+        assert_eq!(Kilograms::UNIT_SHORT_NAME, "kg");
+        assert_eq!(Kilometers::UNIT_NAME, "kilometers");
+        let d: Density<Prefixed<Kilo, Grams>, Meters> = kilograms_per_meter3!(2).as_dyn().into();
+        assert_abs_diff_eq!(d, kilograms_per_meter3!(2));
+    }
+}
@@ -8,6 +8,8 @@ impl Unit for Rankine {
     const UNIT_SUFFIX: &'static str = "°R";
 }
 impl TemperatureUnit for Rankine {
+    const KELVIN_IN_UNIT: f64 = 5. / 9.;
+
     fn convert_to_kelvin(degrees_in: f64) -> f64 {
         degrees_in * 5. / 9.
     }
@@ -22,3 +24,10 @@ macro_rules! rankine {
         $crate::Temperature::<$crate::Rankine>::from(&$num)
     };
 }
+
+#[macro_export]
+macro_rules! rankine_delta {
+    ($num:expr) => {
+        $crate::TemperatureDelta::<$crate::Rankine>::from(&$num)
+    };
+}
@@ -0,0 +1,48 @@
+use crate::{AngleUnit, Unit};
+use std::f64::consts::TAU;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Revolutions;
+impl Unit for Revolutions {
+    const UNIT_NAME: &'static str = "revolutions";
+    const UNIT_SHORT_NAME: &'static str = "rev";
+    const UNIT_SUFFIX: &'static str = "rev";
+}
+impl AngleUnit for Revolutions {
+    const RADIANS_IN_UNIT: f64 = TAU;
+}
+
+#[macro_export]
+macro_rules! revolutions {
+    ($num:expr) => {
+        $crate::Angle::<$crate::Revolutions>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! turns {
+    ($num:expr) => {
+        $crate::Angle::<$crate::Revolutions>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! revolutions_per_minute {
+    ($num:expr) => {
+        $crate::AngularVelocity::<$crate::Revolutions, $crate::Minutes>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! revolutions_per_second {
+    ($num:expr) => {
+        $crate::AngularVelocity::<$crate::Revolutions, $crate::Seconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! revolutions_per_second2 {
+    ($num:expr) => {
+        $crate::AngularAcceleration::<$crate::Revolutions, $crate::Seconds>::from(&$num)
+    };
+}
@@ -0,0 +1,20 @@
+use crate::{FrequencyUnit, Unit};
+
+// Revolutions per minute, as a frequency; see AngularVelocity for rotation rates.
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Rpm;
+impl Unit for Rpm {
+    const UNIT_NAME: &'static str = "revolutions per minute";
+    const UNIT_SHORT_NAME: &'static str = "rpm";
+    const UNIT_SUFFIX: &'static str = "rpm";
+}
+impl FrequencyUnit for Rpm {
+    const HERTZ_IN_UNIT: f64 = 1. / 60.;
+}
+
+#[macro_export]
+macro_rules! rpm {
+    ($num:expr) => {
+        $crate::Frequency::<$crate::Rpm>::from(&$num)
+    };
+}
@@ -17,3 +17,10 @@ macro_rules! seconds {
         $crate::Time::<$crate::Seconds>::from(&$num)
     };
 }
+
+#[macro_export]
+macro_rules! specific_impulse {
+    ($num:expr) => {
+        $crate::SpecificImpulse::<$crate::Seconds>::from(&$num)
+    };
+}
@@ -0,0 +1,34 @@
+use crate::{AngleUnit, Unit};
+use std::f64::consts::PI;
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SecondsOfRightAscension;
+impl Unit for SecondsOfRightAscension {
+    const UNIT_NAME: &'static str = "seconds of right ascension";
+    const UNIT_SHORT_NAME: &'static str = "sRA";
+    const UNIT_SUFFIX: &'static str = "ˢ";
+}
+impl AngleUnit for SecondsOfRightAscension {
+    const RADIANS_IN_UNIT: f64 = PI / 12f64 / 60f64 / 60f64;
+}
+
+#[macro_export]
+macro_rules! ra_seconds {
+    ($num:expr) => {
+        $crate::Angle::<$crate::SecondsOfRightAscension>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! ra_seconds_per_second {
+    ($num:expr) => {
+        $crate::AngularVelocity::<$crate::SecondsOfRightAscension, $crate::Seconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! ra_seconds_per_second2 {
+    ($num:expr) => {
+        $crate::AngularAcceleration::<$crate::SecondsOfRightAscension, $crate::Seconds>::from(&$num)
+    };
+}
@@ -0,0 +1,40 @@
+use crate::{MassUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct ShortTons;
+impl Unit for ShortTons {
+    const UNIT_NAME: &'static str = "short tons";
+    const UNIT_SHORT_NAME: &'static str = "ton";
+    const UNIT_SUFFIX: &'static str = "ton";
+}
+impl MassUnit for ShortTons {
+    const GRAMS_IN_UNIT: f64 = 907_184.74;
+}
+
+#[macro_export]
+macro_rules! short_tons {
+    ($num:expr) => {
+        $crate::Mass::<$crate::ShortTons>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! short_tons_per_yard3 {
+    ($num:expr) => {
+        $crate::Density::<$crate::ShortTons, $crate::Yards>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! short_tons_per_second {
+    ($num:expr) => {
+        $crate::MassRate::<$crate::ShortTons, $crate::Seconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! short_tons_per_hour {
+    ($num:expr) => {
+        $crate::MassRate::<$crate::ShortTons, $crate::Hours>::from(&$num)
+    };
+}
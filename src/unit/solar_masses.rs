@@ -0,0 +1,40 @@
+use crate::{MassUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SolarMasses;
+impl Unit for SolarMasses {
+    const UNIT_NAME: &'static str = "solar masses";
+    const UNIT_SHORT_NAME: &'static str = "M☉";
+    const UNIT_SUFFIX: &'static str = "M☉";
+}
+impl MassUnit for SolarMasses {
+    const GRAMS_IN_UNIT: f64 = 1.988_47e33;
+}
+
+#[macro_export]
+macro_rules! solar_masses {
+    ($num:expr) => {
+        $crate::Mass::<$crate::SolarMasses>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! solar_masses_per_parsec3 {
+    ($num:expr) => {
+        $crate::Density::<$crate::SolarMasses, $crate::Parsecs>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! solar_masses_per_second {
+    ($num:expr) => {
+        $crate::MassRate::<$crate::SolarMasses, $crate::Seconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! solar_masses_per_julian_year {
+    ($num:expr) => {
+        $crate::MassRate::<$crate::SolarMasses, $crate::JulianYears>::from(&$num)
+    };
+}
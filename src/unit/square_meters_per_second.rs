@@ -0,0 +1,19 @@
+use crate::{KinematicViscosityUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct SquareMetersPerSecond;
+impl Unit for SquareMetersPerSecond {
+    const UNIT_NAME: &'static str = "square meters per second";
+    const UNIT_SHORT_NAME: &'static str = "m^2/s";
+    const UNIT_SUFFIX: &'static str = "m^2/s";
+}
+impl KinematicViscosityUnit for SquareMetersPerSecond {
+    const SQUARE_METERS_PER_SECOND_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! meters2_per_second {
+    ($num:expr) => {
+        $crate::KinematicViscosity::<$crate::SquareMetersPerSecond>::from(&$num)
+    };
+}
@@ -0,0 +1,40 @@
+use crate::{MassUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Tonnes;
+impl Unit for Tonnes {
+    const UNIT_NAME: &'static str = "tonnes";
+    const UNIT_SHORT_NAME: &'static str = "t";
+    const UNIT_SUFFIX: &'static str = "t";
+}
+impl MassUnit for Tonnes {
+    const GRAMS_IN_UNIT: f64 = 1_000_000.0;
+}
+
+#[macro_export]
+macro_rules! tonnes {
+    ($num:expr) => {
+        $crate::Mass::<$crate::Tonnes>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! tonnes_per_meter3 {
+    ($num:expr) => {
+        $crate::Density::<$crate::Tonnes, $crate::Meters>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! tonnes_per_second {
+    ($num:expr) => {
+        $crate::MassRate::<$crate::Tonnes, $crate::Seconds>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! tonnes_per_hour {
+    ($num:expr) => {
+        $crate::MassRate::<$crate::Tonnes, $crate::Hours>::from(&$num)
+    };
+}
@@ -0,0 +1,20 @@
+use crate::{PressureUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Torr;
+impl Unit for Torr {
+    const UNIT_NAME: &'static str = "torr";
+    const UNIT_SHORT_NAME: &'static str = "Torr";
+    const UNIT_SUFFIX: &'static str = "Torr";
+}
+// Exactly 1/760 of a standard atmosphere.
+impl PressureUnit for Torr {
+    const PASCALS_IN_UNIT: f64 = 101_325.0 / 760.0;
+}
+
+#[macro_export]
+macro_rules! torr {
+    ($num:expr) => {
+        $crate::Pressure::<$crate::Torr>::from(&$num)
+    };
+}
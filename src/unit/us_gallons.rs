@@ -0,0 +1,30 @@
+use crate::{Feet, Unit, VolumeUnit};
+
+// US liquid gallon: 231 cubic inches
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct UsGallons;
+impl Unit for UsGallons {
+    const UNIT_NAME: &'static str = "US gallons";
+    const UNIT_SHORT_NAME: &'static str = "gal";
+    const UNIT_SUFFIX: &'static str = "gal";
+}
+impl VolumeUnit for UsGallons {
+    const CUBIC_METERS_IN_UNIT: f64 = 0.003_785_411_784;
+    const VOLUME_SUFFIX: &'static str = "";
+
+    type UnitLength = Feet;
+}
+
+#[macro_export]
+macro_rules! us_gallons {
+    ($num:expr) => {
+        $crate::Volume::<$crate::UsGallons>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! us_gallons_per_minute {
+    ($num:expr) => {
+        $crate::VolumeRate::<$crate::UsGallons, $crate::Minutes>::from(&$num)
+    };
+}
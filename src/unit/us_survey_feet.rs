@@ -0,0 +1,40 @@
+use crate::{LengthUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct UsSurveyFeet;
+impl Unit for UsSurveyFeet {
+    const UNIT_NAME: &'static str = "US survey feet";
+    const UNIT_SHORT_NAME: &'static str = "ft(US)";
+    const UNIT_SUFFIX: &'static str = "ft(US)";
+}
+impl LengthUnit for UsSurveyFeet {
+    const METERS_IN_UNIT: f64 = 1_200.0 / 3_937.0;
+}
+
+#[macro_export]
+macro_rules! us_survey_feet {
+    ($num:expr) => {
+        $crate::Length::<$crate::UsSurveyFeet>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! us_survey_feet2 {
+    ($num:expr) => {
+        $crate::Area::<$crate::UsSurveyFeet>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! us_survey_feet3 {
+    ($num:expr) => {
+        $crate::Volume::<$crate::UsSurveyFeet>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! us_survey_feet_per_second {
+    ($num:expr) => {
+        $crate::Velocity::<$crate::UsSurveyFeet, $crate::Seconds>::from(&$num)
+    };
+}
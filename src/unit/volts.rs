@@ -0,0 +1,19 @@
+use crate::{Unit, VoltageUnit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Volts;
+impl Unit for Volts {
+    const UNIT_NAME: &'static str = "volts";
+    const UNIT_SHORT_NAME: &'static str = "V";
+    const UNIT_SUFFIX: &'static str = "V";
+}
+impl VoltageUnit for Volts {
+    const VOLTS_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! volts {
+    ($num:expr) => {
+        $crate::Voltage::<$crate::Volts>::from(&$num)
+    };
+}
@@ -0,0 +1,19 @@
+use crate::{PowerUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Watts;
+impl Unit for Watts {
+    const UNIT_NAME: &'static str = "watts";
+    const UNIT_SHORT_NAME: &'static str = "W";
+    const UNIT_SUFFIX: &'static str = "W";
+}
+impl PowerUnit for Watts {
+    const WATTS_IN_UNIT: f64 = 1.0;
+}
+
+#[macro_export]
+macro_rules! watts {
+    ($num:expr) => {
+        $crate::Power::<$crate::Watts>::from(&$num)
+    };
+}
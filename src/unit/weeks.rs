@@ -0,0 +1,19 @@
+use crate::{TimeUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Weeks;
+impl Unit for Weeks {
+    const UNIT_NAME: &'static str = "weeks";
+    const UNIT_SHORT_NAME: &'static str = "wk";
+    const UNIT_SUFFIX: &'static str = "wk";
+}
+impl TimeUnit for Weeks {
+    const SECONDS_IN_UNIT: f64 = 604_800.;
+}
+
+#[macro_export]
+macro_rules! weeks {
+    ($num:expr) => {
+        $crate::Time::<$crate::Weeks>::from(&$num)
+    };
+}
@@ -0,0 +1,40 @@
+use crate::{LengthUnit, Unit};
+
+#[derive(Clone, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Yards;
+impl Unit for Yards {
+    const UNIT_NAME: &'static str = "yards";
+    const UNIT_SHORT_NAME: &'static str = "yd";
+    const UNIT_SUFFIX: &'static str = "yd";
+}
+impl LengthUnit for Yards {
+    const METERS_IN_UNIT: f64 = 0.914_4;
+}
+
+#[macro_export]
+macro_rules! yards {
+    ($num:expr) => {
+        $crate::Length::<$crate::Yards>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! yards2 {
+    ($num:expr) => {
+        $crate::Area::<$crate::Yards>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! yards3 {
+    ($num:expr) => {
+        $crate::Volume::<$crate::Yards>::from(&$num)
+    };
+}
+
+#[macro_export]
+macro_rules! yards_per_second {
+    ($num:expr) => {
+        $crate::Velocity::<$crate::Yards, $crate::Seconds>::from(&$num)
+    };
+}